@@ -42,6 +42,8 @@ function App() {
   const [burnFromFrom, setBurnFromFrom] = useState('');
   const [burnFromAmount, setBurnFromAmount] = useState(0);
   const [freezeAccountAddr, setFreezeAccountAddr] = useState('');
+  const [freezeReason, setFreezeReason] = useState(0);
+  const [freezeCaseRef, setFreezeCaseRef] = useState('');
   const [unfreezeAccountAddr, setUnfreezeAccountAddr] = useState('');
   const [multisigOwners, setMultisigOwners] = useState('');
   const [multisigThreshold, setMultisigThreshold] = useState(2);
@@ -168,7 +170,7 @@ function App() {
   const handleFreeze = async () => {
     try {
       setLoading(true);
-      await Contract.freezeAccount(publicKey, freezeAccountAddr);
+      await Contract.freezeAccount(publicKey, freezeAccountAddr, freezeReason, freezeCaseRef);
       toast({ title: 'FreezeAccount çağrıldı', status: 'info', duration: 5000, isClosable: true });
     } catch (error) {
       toast({ title: 'FreezeAccount başarısız', description: error.message, status: 'error', duration: 5000, isClosable: true });
@@ -463,6 +465,28 @@ function App() {
                             placeholder="Enter freeze account address"
                           />
                         </FormControl>
+                        <FormControl>
+                          <FormLabel>Freeze Reason Code</FormLabel>
+                          <NumberInput
+                            value={freezeReason}
+                            onChange={(value) => setFreezeReason(value)}
+                            min={0}
+                          >
+                            <NumberInputField />
+                            <NumberInputStepper>
+                              <NumberIncrementStepper />
+                              <NumberDecrementStepper />
+                            </NumberInputStepper>
+                          </NumberInput>
+                        </FormControl>
+                        <FormControl>
+                          <FormLabel>Case Reference</FormLabel>
+                          <Input
+                            value={freezeCaseRef}
+                            onChange={(e) => setFreezeCaseRef(e.target.value)}
+                            placeholder="Enter 32-byte case reference as hex"
+                          />
+                        </FormControl>
                         <Button
                           colorScheme="blue"
                           width="full"
@@ -85,16 +85,24 @@ export const burnFrom = async (spender, from, amount) => {
   return tx.toXDR();
 };
 
-export const freezeAccount = async (admin, account) => {
+export const freezeAccount = async (admin, account, reason, caseRef) => {
   const source = await server.getAccount(admin);
   const contract = new Contract(CONTRACT_ADDRESS);
   const adminAddr = new Address(admin);
   const accountAddr = new Address(account);
+  const reasonVal = nativeToScVal(reason, { type: 'u32' });
+  // Dava referansı 32 baytlık onaltılık (hex) değer olarak girilir
+  const caseRefHex = caseRef.trim().replace(/^0x/, '');
+  if (!/^[0-9a-fA-F]{64}$/.test(caseRefHex)) {
+    throw new Error('Case reference must be 32 bytes (64 hex characters)');
+  }
+  const caseRefBytes = Uint8Array.from(caseRefHex.match(/../g), byte => parseInt(byte, 16));
+  const caseRefVal = nativeToScVal(caseRefBytes);
   const tx = new TransactionBuilder(source, {
     fee: '100',
     networkPassphrase: NETWORK_PASSPHRASE
   })
-    .addOperation(contract.call('freeze_account', adminAddr, accountAddr))
+    .addOperation(contract.call('freeze_account', adminAddr, accountAddr, reasonVal, caseRefVal))
     .setTimeout(30)
     .build();
   return tx.toXDR();
@@ -118,12 +126,13 @@ export const unfreezeAccount = async (admin, account) => {
 export const setupMultisig = async (admin, owners, threshold) => {
   const source = await server.getAccount(admin);
   const contract = new Contract(CONTRACT_ADDRESS);
+  const adminAddr = new Address(admin);
   const ownersArr = Array.isArray(owners) ? owners.map(addr => new Address(addr)) : owners.split(',').map(s => new Address(s.trim()));
   const tx = new TransactionBuilder(source, {
     fee: '100',
     networkPassphrase: NETWORK_PASSPHRASE
   })
-    .addOperation(contract.call('setup_multisig', ownersArr, nativeToScVal(threshold, { type: 'u32' })))
+    .addOperation(contract.call('setup_multisig', adminAddr, ownersArr, nativeToScVal(threshold, { type: 'u32' })))
     .setTimeout(30)
     .build();
   return tx.toXDR();
@@ -132,6 +141,7 @@ export const setupMultisig = async (admin, owners, threshold) => {
 export const proposeMultisigTransaction = async (owner, functionName, to, amount, expiration) => {
   const source = await server.getAccount(owner);
   const contract = new Contract(CONTRACT_ADDRESS);
+  const ownerAddr = new Address(owner);
   const toAddr = new Address(to);
   const amtVal = nativeToScVal(amount, { type: 'i128' });
   const expVal = nativeToScVal(expiration, { type: 'u64' });
@@ -139,7 +149,7 @@ export const proposeMultisigTransaction = async (owner, functionName, to, amount
     fee: '100',
     networkPassphrase: NETWORK_PASSPHRASE
   })
-    .addOperation(contract.call('propose_multisig_transaction', functionName, toAddr, amtVal, expVal))
+    .addOperation(contract.call('propose_multisig_transaction', ownerAddr, functionName, toAddr, amtVal, expVal))
     .setTimeout(30)
     .build();
   return tx.toXDR();
@@ -148,11 +158,12 @@ export const proposeMultisigTransaction = async (owner, functionName, to, amount
 export const approveMultisigTransaction = async (owner, transactionId) => {
   const source = await server.getAccount(owner);
   const contract = new Contract(CONTRACT_ADDRESS);
+  const ownerAddr = new Address(owner);
   const tx = new TransactionBuilder(source, {
     fee: '100',
     networkPassphrase: NETWORK_PASSPHRASE
   })
-    .addOperation(contract.call('approve_multisig_transaction', nativeToScVal(transactionId, { type: 'u64' })))
+    .addOperation(contract.call('approve_multisig_transaction', ownerAddr, nativeToScVal(transactionId, { type: 'u64' })))
     .setTimeout(30)
     .build();
   return tx.toXDR();
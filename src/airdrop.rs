@@ -0,0 +1,49 @@
+use crate::storage_types::{Airdrop, DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
+
+pub fn read_airdrop(e: &Env) -> Option<Airdrop> {
+    let key = DataKey::Airdrop;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_airdrop(e: &Env, airdrop: &Airdrop) {
+    let key = DataKey::Airdrop;
+    e.storage().instance().set(&key, airdrop);
+}
+
+fn read_claimed_word(e: &Env, airdrop_id: u32, word: u32) -> u128 {
+    let key = DataKey::AirdropClaimed(airdrop_id, word);
+    e.storage().persistent().get(&key).unwrap_or(0)
+}
+
+pub fn is_claimed(e: &Env, airdrop_id: u32, index: u32) -> bool {
+    read_claimed_word(e, airdrop_id, index / 128) & (1u128 << (index % 128)) != 0
+}
+
+pub fn set_claimed(e: &Env, airdrop_id: u32, index: u32) {
+    let word = index / 128;
+    let claimed = read_claimed_word(e, airdrop_id, word) | (1u128 << (index % 128));
+    let key = DataKey::AirdropClaimed(airdrop_id, word);
+    e.storage().persistent().set(&key, &claimed);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+pub fn airdrop_leaf(e: &Env, index: u32, account: &Address, amount: i128) -> BytesN<32> {
+    e.crypto()
+        .sha256(&(index, account.clone(), amount).to_xdr(e))
+        .to_bytes()
+}
+
+pub fn verify_merkle_proof(e: &Env, root: &BytesN<32>, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        let (left, right) = if node <= sibling { (node, sibling) } else { (sibling, node) };
+        let mut pair = Bytes::from_array(e, &left.to_array());
+        pair.extend_from_array(&right.to_array());
+        node = e.crypto().sha256(&pair).to_bytes();
+    }
+    node == *root
+}
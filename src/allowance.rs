@@ -1,10 +1,36 @@
-use crate::storage_types::{AllowanceDataKey, AllowanceValue, DataKey};
-use soroban_sdk::{Address, Env};
+use crate::errors::TokenError;
+use crate::storage_types::{
+    AllowanceDataKey, AllowanceInfo, AllowancePage, AllowanceValue, DataKey, BALANCE_BUMP_AMOUNT,
+    BALANCE_LIFETIME_THRESHOLD, MAX_ALLOWANCES_PAGE_SIZE,
+};
+use soroban_sdk::{panic_with_error, Address, Env, IntoVal, Val, Vec};
+
+pub const UNLIMITED_ALLOWANCE: i128 = i128::MAX;
+
+pub fn read_allowance_epoch(e: &Env, from: &Address) -> u32 {
+    let key = DataKey::AllowanceEpoch(from.clone());
+    if let Some(epoch) = e.storage().persistent().get::<_, u32>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        epoch
+    } else {
+        0
+    }
+}
+
+fn read_allowance_epoch_stamp(e: &Env, key: &AllowanceDataKey) -> u32 {
+    let key = DataKey::AllowanceEpochStamp(key.clone());
+    e.storage().temporary().get(&key).unwrap_or(0)
+}
 
 pub fn read_allowance(e: &Env, from: Address, spender: Address) -> AllowanceValue {
-    let key = DataKey::Allowance(AllowanceDataKey { from, spender });
+    let epoch = read_allowance_epoch(e, &from);
+    let allowance_key = AllowanceDataKey { from, spender };
+    let stale = epoch > 0 && read_allowance_epoch_stamp(e, &allowance_key) != epoch;
+    let key = DataKey::Allowance(allowance_key);
     if let Some(allowance) = e.storage().temporary().get::<_, AllowanceValue>(&key) {
-        if allowance.expiration_ledger < e.ledger().sequence() {
+        if allowance.expiration_ledger < e.ledger().sequence() || stale {
             AllowanceValue {
                 amount: 0,
                 expiration_ledger: allowance.expiration_ledger,
@@ -36,7 +62,16 @@ pub fn write_allowance(
         panic!("expiration_ledger is less than ledger seq when amount > 0")
     }
 
-    let key = DataKey::Allowance(AllowanceDataKey { from, spender });
+    update_spender_index(e, &from, &spender, amount > 0);
+
+    let epoch = read_allowance_epoch(e, &from);
+    let allowance_key = AllowanceDataKey { from, spender };
+    let stamp_key = DataKey::AllowanceEpochStamp(allowance_key.clone());
+    if epoch > 0 {
+        e.storage().temporary().set(&stamp_key, &epoch);
+    }
+
+    let key = DataKey::Allowance(allowance_key);
     e.storage().temporary().set(&key.clone(), &allowance);
 
     if amount > 0 {
@@ -44,7 +79,83 @@ pub fn write_allowance(
             .checked_sub(e.ledger().sequence())
             .unwrap();
 
-        e.storage().temporary().extend_ttl(&key, live_for, live_for)
+        e.storage().temporary().extend_ttl(&key, live_for, live_for);
+        if epoch > 0 {
+            e.storage().temporary().extend_ttl(&stamp_key, live_for, live_for);
+        }
+    }
+}
+
+fn read_spender_count(e: &Env, from: &Address) -> u32 {
+    let key = DataKey::AllowanceSpenderCount(from.clone());
+    e.storage().persistent().get(&key).unwrap_or(0)
+}
+
+fn write_persistent<V: IntoVal<Env, Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    e.storage()
+        .persistent()
+        .extend_ttl(key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+fn remove_spender_slot(e: &Env, key: AllowanceDataKey, slot: u32) {
+    e.storage()
+        .persistent()
+        .remove(&DataKey::AllowanceSpenderAt(key.from.clone(), slot));
+    e.storage()
+        .persistent()
+        .remove(&DataKey::AllowanceSpenderSlot(key));
+}
+
+// Her (from, spender) çifti kendi sırasını ayrı bir kayıtta tutar; dizin büyüklüğü izinleri sınırlamaz
+fn update_spender_index(e: &Env, from: &Address, spender: &Address, active: bool) {
+    let key = AllowanceDataKey {
+        from: from.clone(),
+        spender: spender.clone(),
+    };
+    let slot_key = DataKey::AllowanceSpenderSlot(key.clone());
+    match (e.storage().persistent().get::<_, u32>(&slot_key), active) {
+        (None, true) => {
+            let slot = read_spender_count(e, from);
+            // Sıra numaraları tükenirse izin yine yazılır, yalnızca listelenmez
+            if let Some(next) = slot.checked_add(1) {
+                write_persistent(e, &DataKey::AllowanceSpenderCount(from.clone()), &next);
+                write_persistent(e, &DataKey::AllowanceSpenderAt(from.clone(), slot), spender);
+                write_persistent(e, &slot_key, &slot);
+            }
+        }
+        (Some(slot), false) => remove_spender_slot(e, key, slot),
+        _ => {}
+    }
+}
+
+pub fn read_allowances_of(e: &Env, from: Address, start: u32, limit: u32) -> AllowancePage {
+    if limit == 0 || limit > MAX_ALLOWANCES_PAGE_SIZE {
+        panic!("page limit must be between 1 and {}", MAX_ALLOWANCES_PAGE_SIZE);
+    }
+    let count = read_spender_count(e, &from);
+    let end = start.saturating_add(limit).min(count);
+    let mut allowances = Vec::new(e);
+    for slot in start..end {
+        let at_key = DataKey::AllowanceSpenderAt(from.clone(), slot);
+        let Some(spender) = e.storage().persistent().get::<_, Address>(&at_key) else {
+            continue;
+        };
+        let allowance = read_allowance(e, from.clone(), spender.clone());
+        if allowance.amount > 0 {
+            allowances.push_back(AllowanceInfo {
+                spender,
+                amount: allowance.amount,
+                expiration_ledger: allowance.expiration_ledger,
+            });
+        } else {
+            // Süresi dolan ya da iptal edilen izinler sorgu sırasında dizinden silinir
+            remove_spender_slot(e, AllowanceDataKey { from: from.clone(), spender }, slot);
+        }
+    }
+    AllowancePage {
+        allowances,
+        next: if end < count { Some(end) } else { None },
     }
 }
 
@@ -53,11 +164,62 @@ pub fn spend_allowance(e: &Env, from: Address, spender: Address, amount: i128) {
     if allowance.amount < amount {
         panic!("insufficient allowance");
     }
-    write_allowance(
-        e,
-        from,
-        spender,
-        allowance.amount - amount,
-        allowance.expiration_ledger,
-    );
-}
\ No newline at end of file
+    if allowance.amount == UNLIMITED_ALLOWANCE {
+        return;
+    }
+    let remaining = allowance
+        .amount
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic_with_error!(e, TokenError::AllowanceOverflow));
+    write_allowance(e, from, spender, remaining, allowance.expiration_ledger);
+}
+
+pub fn increase_allowance(
+    e: &Env,
+    from: Address,
+    spender: Address,
+    amount: i128,
+    expiration_ledger: u32,
+) -> i128 {
+    let allowance = read_allowance(e, from.clone(), spender.clone());
+    // Sınırsız izin yalnızca açıkça istenirse verilir; toplamı sentinel değere ulaşan sınırlı izin taşma sayılır
+    let new_amount = if allowance.amount == UNLIMITED_ALLOWANCE || amount == UNLIMITED_ALLOWANCE {
+        UNLIMITED_ALLOWANCE
+    } else {
+        allowance
+            .amount
+            .checked_add(amount)
+            .filter(|sum| *sum != UNLIMITED_ALLOWANCE)
+            .unwrap_or_else(|| panic_with_error!(e, TokenError::AllowanceOverflow))
+    };
+    write_allowance(e, from, spender, new_amount, expiration_ledger);
+    new_amount
+}
+
+pub fn decrease_allowance(e: &Env, from: Address, spender: Address, amount: i128) -> AllowanceValue {
+    let allowance = read_allowance(e, from.clone(), spender.clone());
+    if allowance.amount < amount {
+        panic!("allowance decreased below zero");
+    }
+    let new_amount = allowance
+        .amount
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic_with_error!(e, TokenError::AllowanceOverflow));
+    write_allowance(e, from, spender, new_amount, allowance.expiration_ledger);
+    AllowanceValue {
+        amount: new_amount,
+        ..allowance
+    }
+}
+
+pub fn revoke_all_allowances(e: &Env, from: &Address) -> u32 {
+    let epoch = read_allowance_epoch(e, from)
+        .checked_add(1)
+        .unwrap_or_else(|| panic_with_error!(e, TokenError::AllowanceOverflow));
+    let key = DataKey::AllowanceEpoch(from.clone());
+    e.storage().persistent().set(&key, &epoch);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    epoch
+}
@@ -1,5 +1,6 @@
+use crate::errors::TokenError;
 use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{panic_with_error, Address, Env};
 
 pub fn read_balance(e: &Env, addr: Address) -> i128 {
     let key = DataKey::Balance(addr);
@@ -23,7 +24,10 @@ fn write_balance(e: &Env, addr: Address, amount: i128) {
 
 pub fn receive_balance(e: &Env, addr: Address, amount: i128) {
     let balance = read_balance(e, addr.clone());
-    write_balance(e, addr, balance + amount);
+    let balance = balance
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, TokenError::BalanceOverflow));
+    write_balance(e, addr, balance);
 }
 
 pub fn spend_balance(e: &Env, addr: Address, amount: i128) {
@@ -31,5 +35,8 @@ pub fn spend_balance(e: &Env, addr: Address, amount: i128) {
     if balance < amount {
         panic!("insufficient balance");
     }
-    write_balance(e, addr, balance - amount);
+    let balance = balance
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic_with_error!(e, TokenError::BalanceOverflow));
+    write_balance(e, addr, balance);
 }
\ No newline at end of file
@@ -0,0 +1,12 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TokenError {
+    BalanceOverflow = 1,
+    AllowanceOverflow = 2,
+    SupplyOverflow = 3,
+    MinterQuotaOverflow = 4,
+    NegativeSupply = 5,
+}
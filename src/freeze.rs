@@ -0,0 +1,59 @@
+use crate::storage_types::{
+    DataKey, FreezeRecord, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD, LEGACY_FREEZE_REASON,
+};
+use soroban_sdk::{Address, BytesN, Env, TryFromVal, Val};
+
+pub fn is_frozen(e: &Env, account: &Address) -> bool {
+    read_freeze_record(e, account).is_some()
+}
+
+pub fn read_freeze_record(e: &Env, account: &Address) -> Option<FreezeRecord> {
+    let key = DataKey::Frozen(account.clone());
+    if let Some(record) = e.storage().persistent().get::<DataKey, FreezeRecord>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        Some(record)
+    } else {
+        read_legacy_freeze_record(e, account)
+    }
+}
+
+pub fn write_freeze_record(e: &Env, account: &Address, record: &FreezeRecord) {
+    let key = DataKey::Frozen(account.clone());
+    e.storage().persistent().set(&key, record);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    remove_legacy_freeze_record(e, account);
+}
+
+pub fn remove_freeze_record(e: &Env, account: &Address) {
+    let key = DataKey::Frozen(account.clone());
+    e.storage().persistent().remove(&key);
+    remove_legacy_freeze_record(e, account);
+}
+
+pub fn remove_legacy_freeze_record(e: &Env, account: &Address) {
+    let key = DataKey::Frozen(account.clone());
+    if e.storage().instance().has(&key) {
+        e.storage().instance().remove(&key);
+    }
+}
+
+pub fn read_legacy_freeze_record(e: &Env, account: &Address) -> Option<FreezeRecord> {
+    let key = DataKey::Frozen(account.clone());
+    let value = e.storage().instance().get::<_, Val>(&key)?;
+    if let Ok(record) = FreezeRecord::try_from_val(e, &value) {
+        return Some(record);
+    }
+    match bool::try_from_val(e, &value) {
+        Ok(true) => Some(FreezeRecord {
+            reason: LEGACY_FREEZE_REASON,
+            case_ref: BytesN::from_array(e, &[0; 32]),
+            actor: e.current_contract_address(),
+            timestamp: 0,
+        }),
+        _ => None,
+    }
+}
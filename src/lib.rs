@@ -1,15 +1,28 @@
 #![no_std]
 
 mod admin;
+mod airdrop;
 mod allowance;
 mod balance;
 mod contract;
+mod errors;
+mod freeze;
 mod metadata;
+mod minter;
+mod pause;
+mod role;
+mod schema;
+mod signature;
 mod storage_types;
+mod supply;
+mod upgrade;
 
 #[cfg(test)]
 mod test;
 
 // Re-export the Token struct and TokenClient for external users
+pub use crate::allowance::UNLIMITED_ALLOWANCE;
 pub use crate::contract::Token;
-pub use crate::contract::TokenClient;
\ No newline at end of file
+pub use crate::contract::TokenClient;
+pub use crate::errors::TokenError;
+pub use crate::storage_types::LEGACY_FREEZE_REASON;
\ No newline at end of file
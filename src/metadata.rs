@@ -1,6 +1,15 @@
-use soroban_sdk::{Env, String};
+use crate::storage_types::{
+    DataKey, MetadataRecord, TokenInfo, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+    MAX_METADATA_HISTORY,
+};
+use soroban_sdk::{Env, String, Vec};
 use soroban_token_sdk::{metadata::TokenMetadata, TokenUtils};
 
+pub fn read_metadata(e: &Env) -> TokenMetadata {
+    let util = TokenUtils::new(e);
+    util.metadata().get_metadata()
+}
+
 pub fn read_decimal(e: &Env) -> u32 {
     let util = TokenUtils::new(e);
     util.metadata().get_metadata().decimal
@@ -19,4 +28,40 @@ pub fn read_symbol(e: &Env) -> String {
 pub fn write_metadata(e: &Env, metadata: TokenMetadata) {
     let util = TokenUtils::new(e);
     util.metadata().set_metadata(&metadata);
-}
\ No newline at end of file
+}
+
+pub fn read_metadata_history(e: &Env) -> Vec<MetadataRecord> {
+    let key = DataKey::MetadataHistory;
+    e.storage().instance().get(&key).unwrap_or_else(|| Vec::new(e))
+}
+
+// En eski kayıt atılarak yalnızca son MAX_METADATA_HISTORY kayıt tutulur
+pub fn push_metadata_history(e: &Env, record: MetadataRecord) {
+    let key = DataKey::MetadataHistory;
+    let mut history = read_metadata_history(e);
+    if history.len() >= MAX_METADATA_HISTORY {
+        history.pop_front();
+    }
+    history.push_back(record);
+    e.storage().instance().set(&key, &history);
+}
+
+// Her çağrıda yüklenen instance depolamayı büyütmemesi için persistent depolamada tutulur
+pub fn read_token_info(e: &Env) -> Option<TokenInfo> {
+    let key = DataKey::TokenInfo;
+    let info = e.storage().persistent().get(&key);
+    if info.is_some() {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    }
+    info
+}
+
+pub fn write_token_info(e: &Env, info: &TokenInfo) {
+    let key = DataKey::TokenInfo;
+    e.storage().persistent().set(&key, info);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
@@ -0,0 +1,79 @@
+use crate::errors::TokenError;
+use crate::storage_types::{
+    DataKey, MintBucket, MinterAllowance, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD,
+    MINTER_WINDOW_BUCKETS,
+};
+use soroban_sdk::{panic_with_error, Address, Env, Vec};
+
+pub fn read_minter_allowance(e: &Env, minter: &Address) -> Option<MinterAllowance> {
+    let key = DataKey::Minter(minter.clone());
+    let mut allowance = e.storage().persistent().get::<_, MinterAllowance>(&key)?;
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+
+    // Kayan pencere: son period_ledgers ledger içinde basım içeren dilimler sayılır
+    let sequence = u64::from(e.ledger().sequence());
+    let period = u64::from(allowance.period_ledgers);
+    let mut buckets = Vec::new(e);
+    let mut used: i128 = 0;
+    for bucket in allowance.buckets.iter() {
+        if u64::from(bucket.last_ledger) + period > sequence {
+            used = used
+                .checked_add(bucket.amount)
+                .unwrap_or_else(|| panic_with_error!(e, TokenError::MinterQuotaOverflow));
+            buckets.push_back(bucket);
+        }
+    }
+    allowance.buckets = buckets;
+    allowance.used = used;
+    Some(allowance)
+}
+
+pub fn write_minter_allowance(e: &Env, minter: &Address, allowance: &MinterAllowance) {
+    let key = DataKey::Minter(minter.clone());
+    e.storage().persistent().set(&key, allowance);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+pub fn remove_minter_allowance(e: &Env, minter: &Address) {
+    let key = DataKey::Minter(minter.clone());
+    e.storage().persistent().remove(&key);
+}
+
+pub fn spend_minter_allowance(e: &Env, minter: &Address, mut allowance: MinterAllowance, amount: i128) {
+    let used = allowance
+        .used
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, TokenError::MinterQuotaOverflow));
+    if used > allowance.cap {
+        panic!("minter quota exceeded");
+    }
+    allowance.used = used;
+
+    // Aynı dilimdeki basımlar birleştirilir; böylece pencere başına kayıt sayısı sınırlı kalır
+    let sequence = e.ledger().sequence();
+    let bucket_len = allowance.period_ledgers.div_ceil(MINTER_WINDOW_BUCKETS).max(1);
+    let last = allowance
+        .buckets
+        .len()
+        .checked_sub(1)
+        .map(|index| (index, allowance.buckets.get_unchecked(index)));
+    match last {
+        Some((index, mut bucket))
+            if u64::from(sequence) < u64::from(bucket.first_ledger) + u64::from(bucket_len) =>
+        {
+            bucket.last_ledger = sequence;
+            bucket.amount += amount;
+            allowance.buckets.set(index, bucket);
+        }
+        _ => allowance.buckets.push_back(MintBucket {
+            first_ledger: sequence,
+            last_ledger: sequence,
+            amount,
+        }),
+    }
+    write_minter_allowance(e, minter, &allowance);
+}
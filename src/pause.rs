@@ -0,0 +1,35 @@
+use crate::storage_types::DataKey;
+use soroban_sdk::Env;
+
+pub const PAUSE_MINT: u32 = 1 << 0;
+pub const PAUSE_TRANSFER: u32 = 1 << 1;
+pub const PAUSE_TRANSFER_FROM: u32 = 1 << 2;
+pub const PAUSE_BURN: u32 = 1 << 3;
+pub const PAUSE_BURN_FROM: u32 = 1 << 4;
+pub const PAUSE_MULTISIG_EXECUTE: u32 = 1 << 5;
+pub const PAUSE_ALL_FUNCTIONS: u32 = PAUSE_MINT
+    | PAUSE_TRANSFER
+    | PAUSE_TRANSFER_FROM
+    | PAUSE_BURN
+    | PAUSE_BURN_FROM
+    | PAUSE_MULTISIG_EXECUTE;
+
+pub fn is_paused(e: &Env) -> bool {
+    let key = DataKey::Paused;
+    e.storage().instance().get(&key).unwrap_or(false)
+}
+
+pub fn write_paused(e: &Env, paused: bool) {
+    let key = DataKey::Paused;
+    e.storage().instance().set(&key, &paused);
+}
+
+pub fn read_paused_functions(e: &Env) -> u32 {
+    let key = DataKey::PausedFunctions;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn write_paused_functions(e: &Env, mask: u32) {
+    let key = DataKey::PausedFunctions;
+    e.storage().instance().set(&key, &mask);
+}
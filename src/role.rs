@@ -0,0 +1,40 @@
+use crate::storage_types::{DataKey, Role, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Env};
+
+pub fn has_role(e: &Env, role: Role, account: &Address) -> bool {
+    let key = DataKey::Role(role, account.clone());
+    if e.storage().persistent().has(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        true
+    } else {
+        false
+    }
+}
+
+pub fn write_role(e: &Env, role: Role, account: &Address) {
+    let key = DataKey::Role(role, account.clone());
+    e.storage().persistent().set(&key, &true);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+pub fn remove_role(e: &Env, role: Role, account: &Address) {
+    let key = DataKey::Role(role, account.clone());
+    e.storage().persistent().remove(&key);
+}
+
+pub fn read_role_admin(e: &Env, role: Role) -> Option<Role> {
+    let key = DataKey::RoleAdmin(role);
+    e.storage().instance().get(&key)
+}
+
+pub fn write_role_admin(e: &Env, role: Role, admin_role: &Option<Role>) {
+    let key = DataKey::RoleAdmin(role);
+    match admin_role {
+        Some(admin_role) => e.storage().instance().set(&key, admin_role),
+        None => e.storage().instance().remove(&key),
+    }
+}
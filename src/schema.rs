@@ -0,0 +1,12 @@
+use crate::storage_types::{DataKey, LEGACY_SCHEMA_VERSION};
+use soroban_sdk::Env;
+
+pub fn read_schema_version(e: &Env) -> u32 {
+    let key = DataKey::SchemaVersion;
+    e.storage().instance().get(&key).unwrap_or(LEGACY_SCHEMA_VERSION)
+}
+
+pub fn write_schema_version(e: &Env, version: u32) {
+    let key = DataKey::SchemaVersion;
+    e.storage().instance().set(&key, &version);
+}
@@ -0,0 +1,47 @@
+use crate::storage_types::{DataKey, BALANCE_BUMP_AMOUNT, BALANCE_LIFETIME_THRESHOLD};
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+pub fn read_public_key(e: &Env, account: &Address) -> Option<BytesN<32>> {
+    let key = DataKey::PublicKey(account.clone());
+    let public_key = e.storage().persistent().get(&key)?;
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+    Some(public_key)
+}
+
+pub fn write_public_key(e: &Env, account: &Address, public_key: &BytesN<32>) {
+    let key = DataKey::PublicKey(account.clone());
+    e.storage().persistent().set(&key, public_key);
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+pub fn read_nonce(e: &Env, account: &Address) -> u64 {
+    let key = DataKey::Nonce(account.clone());
+    if let Some(nonce) = e.storage().persistent().get::<_, u64>(&key) {
+        e.storage()
+            .persistent()
+            .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+        nonce
+    } else {
+        0
+    }
+}
+
+pub fn consume_nonce(e: &Env, account: &Address, nonce: u64) {
+    if nonce != read_nonce(e, account) {
+        panic!("invalid nonce");
+    }
+    let key = DataKey::Nonce(account.clone());
+    e.storage().persistent().set(&key, &(nonce + 1));
+    e.storage()
+        .persistent()
+        .extend_ttl(&key, BALANCE_LIFETIME_THRESHOLD, BALANCE_BUMP_AMOUNT);
+}
+
+pub fn verify_signature(e: &Env, account: &Address, payload: &Bytes, signature: &BytesN<64>) {
+    let public_key = read_public_key(e, account).unwrap_or_else(|| panic!("public key not registered"));
+    e.crypto().ed25519_verify(&public_key, payload, signature);
+}
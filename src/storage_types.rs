@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address};
+use soroban_sdk::{contracttype, Address, BytesN, String, Vec};
 
 pub(crate) const DAY_IN_LEDGERS: u32 = 17280;
 pub(crate) const INSTANCE_BUMP_AMOUNT: u32 = 7 * DAY_IN_LEDGERS;
@@ -7,6 +7,19 @@ pub(crate) const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_I
 pub(crate) const BALANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
 pub(crate) const BALANCE_LIFETIME_THRESHOLD: u32 = BALANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
 
+pub(crate) const MAX_METADATA_HISTORY: u32 = 5;
+pub(crate) const MAX_TOKEN_INFO_FIELD_LEN: u32 = 256;
+pub(crate) const MINTER_WINDOW_BUCKETS: u32 = 16;
+pub(crate) const MAX_ALLOWANCES_PAGE_SIZE: u32 = 10;
+
+// Sürüm 1: dondurma bayrakları instance depolamada `bool` olarak
+// Sürüm 2: dondurma kayıtları persistent depolamada
+pub(crate) const LEGACY_SCHEMA_VERSION: u32 = 1;
+pub(crate) const CURRENT_SCHEMA_VERSION: u32 = 2;
+
+// Gerekçesi bilinmeyen eski dondurma bayraklarından üretilen kayıtların gerekçe kodu
+pub const LEGACY_FREEZE_REASON: u32 = u32::MAX;
+
 #[derive(Clone)]
 #[contracttype]
 pub struct AllowanceDataKey {
@@ -20,6 +33,97 @@ pub struct AllowanceValue {
     pub expiration_ledger: u32,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AllowanceInfo {
+    pub spender: Address,
+    pub amount: i128,
+    pub expiration_ledger: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AllowancePage {
+    pub allowances: Vec<AllowanceInfo>,
+    pub next: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct FreezeRecord {
+    pub reason: u32,
+    pub case_ref: BytesN<32>,
+    pub actor: Address,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct PendingAdmin {
+    pub new_admin: Address,
+    pub expiration_ledger: Option<u32>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MintBucket {
+    pub first_ledger: u32,
+    pub last_ledger: u32,
+    pub amount: i128,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MinterAllowance {
+    pub cap: i128,
+    pub period_ledgers: u32,
+    pub used: i128,
+    pub buckets: Vec<MintBucket>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MultiSigConfig {
+    pub owners: Vec<Address>,
+    pub threshold: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct MetadataRecord {
+    pub name: String,
+    pub symbol: String,
+    pub replaced_ledger: u32,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct TokenInfo {
+    pub logo_uri: String,
+    pub home_domain: String,
+    pub description: String,
+    pub issuer_contact: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct Airdrop {
+    pub id: u32,
+    pub merkle_root: BytesN<32>,
+    pub deadline: u32,
+    pub remaining: i128,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum Role {
+    Minter,
+    Burner,
+    Freezer,
+    Pauser,
+    MultisigManager,
+}
+
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
@@ -34,5 +138,27 @@ pub enum DataKey {
     MultiSigThreshold,           // Gerekli imza sayısı
     MultiSigTransaction(u64),    // İşlem ID'sine göre işlem
     MultiSigApproval(u64),       // İşlem onayları
+    MultiSigPayload(u64),        // İşlemin ek argümanları
     TotalTransfers,
+    Paused,
+    PausedFunctions,
+    Role(Role, Address),
+    RoleAdmin(Role),
+    PendingAdmin,
+    AdminRenounced,
+    Minter(Address),
+    TotalSupply,
+    MaxSupply,
+    MetadataHistory,
+    TokenInfo,
+    WasmHash,
+    SchemaVersion,
+    AllowanceSpenderCount(Address),        // Hesaba ayrılan dizin sıralarının sayısı
+    AllowanceSpenderAt(Address, u32),      // Dizin sırasındaki harcayıcı
+    AllowanceSpenderSlot(AllowanceDataKey), // Harcayıcının dizindeki sırası
+    AllowanceEpoch(Address),
+    AllowanceEpochStamp(AllowanceDataKey), // İznin verildiği dönem; 0 ise yazılmaz
+    PublicKey(Address),
+    Airdrop,
+    AirdropClaimed(u32, u32),    // Airdrop ID'si ve 128 bitlik talep kelimesi
 }
\ No newline at end of file
@@ -0,0 +1,45 @@
+use crate::errors::TokenError;
+use crate::storage_types::DataKey;
+use soroban_sdk::{panic_with_error, Env};
+
+pub fn read_total_supply(e: &Env) -> i128 {
+    let key = DataKey::TotalSupply;
+    e.storage().instance().get(&key).unwrap_or(0)
+}
+
+pub fn write_total_supply(e: &Env, amount: i128) {
+    let key = DataKey::TotalSupply;
+    e.storage().instance().set(&key, &amount);
+}
+
+pub fn read_max_supply(e: &Env) -> Option<i128> {
+    let key = DataKey::MaxSupply;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_max_supply(e: &Env, max_supply: i128) {
+    let key = DataKey::MaxSupply;
+    e.storage().instance().set(&key, &max_supply);
+}
+
+pub fn increase_supply(e: &Env, amount: i128) {
+    let supply = read_total_supply(e)
+        .checked_add(amount)
+        .unwrap_or_else(|| panic_with_error!(e, TokenError::SupplyOverflow));
+    if let Some(max_supply) = read_max_supply(e) {
+        if supply > max_supply {
+            panic!("max supply exceeded");
+        }
+    }
+    write_total_supply(e, supply);
+}
+
+pub fn decrease_supply(e: &Env, amount: i128) {
+    let supply = read_total_supply(e)
+        .checked_sub(amount)
+        .unwrap_or_else(|| panic_with_error!(e, TokenError::SupplyOverflow));
+    if supply < 0 {
+        panic_with_error!(e, TokenError::NegativeSupply);
+    }
+    write_total_supply(e, supply);
+}
@@ -1,18 +1,49 @@
 #![cfg(test)]
 extern crate std;
 
-use crate::{contract::Token, TokenClient};
-use crate::storage_types::DataKey;
+use crate::{contract::{Token, MAX_BATCH_SIZE, TX_MAX_READ_LEDGER_ENTRIES, TX_MAX_WRITE_LEDGER_ENTRIES}, TokenClient, TokenError, LEGACY_FREEZE_REASON, UNLIMITED_ALLOWANCE};
+use crate::pause::{PAUSE_BURN, PAUSE_MINT, PAUSE_MULTISIG_EXECUTE, PAUSE_TRANSFER, PAUSE_TRANSFER_FROM};
+use crate::storage_types::{
+    AllowanceDataKey, AllowanceInfo, AllowancePage, AllowanceValue, DataKey, FreezeRecord, MultiSigConfig,
+    PendingAdmin, Role, TokenInfo, MAX_ALLOWANCES_PAGE_SIZE, MAX_TOKEN_INFO_FIELD_LEN,
+};
+use ed25519_dalek::{Signer, SigningKey};
+use proptest::prelude::*;
 use soroban_sdk::{
     symbol_short,
-    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, MockAuth, MockAuthInvoke, Ledger, LedgerInfo},
-    Address, Env, IntoVal, Symbol, String, vec, Vec,
+    testutils::{Address as _, AuthorizedFunction, AuthorizedInvocation, EnvTestConfig, Events, Ledger, MockAuth, MockAuthInvoke},
+    vec, xdr::ToXdr, Address, Bytes, BytesN, Env, IntoVal, Symbol, String, TryFromVal, Vec,
 };
 
 fn create_token<'a>(e: &Env, admin: &Address) -> TokenClient<'a> {
-    let token = TokenClient::new(e, &e.register(Token {}, ()));
-    token.initialize(admin, &7, &"name".into_val(e), &"symbol".into_val(e));
-    token
+    create_token_with(e, admin, None, None, None)
+}
+
+fn create_token_with<'a>(
+    e: &Env,
+    admin: &Address,
+    max_supply: Option<i128>,
+    initial_multisig: Option<MultiSigConfig>,
+    allocations: Option<Vec<(Address, i128)>>,
+) -> TokenClient<'a> {
+    let args = (
+        admin.clone(),
+        7_u32,
+        String::from_str(e, "name"),
+        String::from_str(e, "symbol"),
+        max_supply,
+        initial_multisig,
+        allocations,
+    );
+    TokenClient::new(e, &e.register(Token {}, args))
+}
+
+// Kontratın kendisini tek sahip olarak ayarlar; öneri ve onay kontrat adına yapılır
+// Tek sahipli, eşiği 1 olan çoklu imza kurar ve sahibi döndürür
+fn setup_single_owner_multisig(e: &Env, token: &TokenClient, admin: &Address) -> Address {
+    let owner = Address::generate(e);
+    token.setup_multisig(admin, &Vec::from_array(e, [owner.clone()]), &1);
+    owner
 }
 
 #[test]
@@ -27,7 +58,7 @@ fn test() {
     let user3 = Address::generate(&e);
     let token = create_token(&e, &admin1);
 
-    token.mint(&user1, &1000);
+    token.mint(&admin1, &user1, &1000);
     assert_eq!(
         e.auths(),
         std::vec![(
@@ -36,7 +67,7 @@ fn test() {
                 function: AuthorizedFunction::Contract((
                     token.address.clone(),
                     symbol_short!("mint"),
-                    (&user1, 1000_i128).into_val(&e),
+                    (&admin1, &user1, 1000_i128).into_val(&e),
                 )),
                 sub_invocations: std::vec![]
             }
@@ -147,7 +178,7 @@ fn test_burn() {
     let user2 = Address::generate(&e);
     let token = create_token(&e, &admin);
 
-    token.mint(&user1, &1000);
+    token.mint(&admin, &user1, &1000);
     assert_eq!(token.balance(&user1), 1000);
 
     token.approve(&user1, &user2, &500, &200);
@@ -204,7 +235,7 @@ fn transfer_insufficient_balance() {
     let user2 = Address::generate(&e);
     let token = create_token(&e, &admin);
 
-    token.mint(&user1, &1000);
+    token.mint(&admin, &user1, &1000);
     assert_eq!(token.balance(&user1), 1000);
 
     token.transfer(&user1, &user2, &1001);
@@ -222,7 +253,7 @@ fn transfer_from_insufficient_allowance() {
     let user3 = Address::generate(&e);
     let token = create_token(&e, &admin);
 
-    token.mint(&user1, &1000);
+    token.mint(&admin, &user1, &1000);
     assert_eq!(token.balance(&user1), 1000);
 
     token.approve(&user1, &user3, &100, &200);
@@ -232,154 +263,2631 @@ fn transfer_from_insufficient_allowance() {
 }
 
 #[test]
-#[should_panic(expected = "already initialized")]
-fn initialize_already_initialized() {
+fn increase_and_decrease_allowance() {
     let e = Env::default();
+    e.mock_all_auths();
+
     let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
     let token = create_token(&e, &admin);
 
-    token.initialize(&admin, &10, &"name".into_val(&e), &"symbol".into_val(&e));
+    token.approve(&user1, &user2, &100, &200);
+
+    token.increase_allowance(&user1, &user2, &50, &300);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "increase_allowance"),
+                    (&user1, &user2, 50_i128, 300_u32).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("approve"), user1.clone(), user2.clone()).into_val(&e));
+    assert_eq!(<(i128, u32)>::try_from_val(&e, &data).unwrap(), (150, 300));
+    assert_eq!(token.allowance(&user1, &user2), 150);
+
+    token.decrease_allowance(&user1, &user2, &120);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("approve"), user1.clone(), user2.clone()).into_val(&e));
+    assert_eq!(<(i128, u32)>::try_from_val(&e, &data).unwrap(), (30, 300));
+    assert_eq!(token.allowance(&user1, &user2), 30);
+
+    // Süresi dolmuş izin sıfırdan artırılır
+    e.ledger().set_sequence_number(301);
+    token.increase_allowance(&user1, &user2, &10, &400);
+    assert_eq!(token.allowance(&user1, &user2), 10);
+}
+
+// Tüm sayfaları dolaşarak hesabın geçerli izinlerini toplar
+fn all_allowances(e: &Env, token: &TokenClient, from: &Address) -> Vec<AllowanceInfo> {
+    let mut allowances = Vec::new(e);
+    let mut start = Some(0);
+    while let Some(cursor) = start {
+        let page = token.allowances_of(from, &cursor, &MAX_ALLOWANCES_PAGE_SIZE);
+        allowances.append(&page.allowances);
+        start = page.next;
+    }
+    allowances
 }
 
 #[test]
-#[should_panic(expected = "Decimal must fit in a u8")]
-fn decimal_is_over_max() {
+fn allowances_of_lists_active_spenders() {
     let e = Env::default();
+    e.mock_all_auths();
+
     let admin = Address::generate(&e);
-    let token = TokenClient::new(&e, &e.register(Token {}, ()));
-    token.initialize(
-        &admin,
-        &(u32::from(u8::MAX) + 1),
-        &"name".into_val(&e),
-        &"symbol".into_val(&e),
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let spender1 = Address::generate(&e);
+    let spender2 = Address::generate(&e);
+    let spender3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
+
+    assert_eq!(
+        token.allowances_of(&user1, &0, &MAX_ALLOWANCES_PAGE_SIZE),
+        AllowancePage { allowances: Vec::new(&e), next: None }
     );
+
+    token.approve(&user1, &spender1, &100, &200);
+    token.approve(&user1, &spender2, &50, &100);
+    token.approve(&user1, &spender3, &70, &300);
+    token.approve(&user2, &spender1, &10, &200);
+    assert_eq!(
+        all_allowances(&e, &token, &user1),
+        vec![
+            &e,
+            AllowanceInfo { spender: spender1.clone(), amount: 100, expiration_ledger: 200 },
+            AllowanceInfo { spender: spender2.clone(), amount: 50, expiration_ledger: 100 },
+            AllowanceInfo { spender: spender3.clone(), amount: 70, expiration_ledger: 300 },
+        ]
+    );
+
+    // Sıfırlanan, tamamen harcanan ve süresi dolan izinler listelenmez
+    token.approve(&user1, &spender1, &0, &200);
+    token.transfer_from(&spender3, &user1, &user2, &70);
+    e.ledger().set_sequence_number(101);
+    assert_eq!(all_allowances(&e, &token, &user1), Vec::new(&e));
+
+    token.approve(&user1, &spender1, &30, &200);
+    assert_eq!(
+        all_allowances(&e, &token, &user1),
+        vec![&e, AllowanceInfo { spender: spender1.clone(), amount: 30, expiration_ledger: 200 }]
+    );
+}
+
+// Dizinde saklanan harcayıcılar (süresi dolanlar dahil)
+fn indexed_spenders(e: &Env, token: &TokenClient, from: &Address) -> Vec<Address> {
+    e.as_contract(&token.address, || {
+        let count: u32 = e
+            .storage()
+            .persistent()
+            .get(&DataKey::AllowanceSpenderCount(from.clone()))
+            .unwrap_or(0);
+        let mut spenders = Vec::new(e);
+        for slot in 0..count {
+            if let Some(spender) = e.storage().persistent().get(&DataKey::AllowanceSpenderAt(from.clone(), slot)) {
+                spenders.push_back(spender);
+            }
+        }
+        spenders
+    })
 }
+
 #[test]
-#[should_panic(expected = "account is frozen")]
-fn transfer_from_frozen_account_should_fail() {
+fn spender_index_prunes_expired_allowances() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    for _ in 0..MAX_ALLOWANCES_PAGE_SIZE {
+        token.approve(&user, &Address::generate(&e), &10, &100);
+    }
+    e.ledger().set_sequence_number(101);
+    let fresh = Address::generate(&e);
+    token.approve(&user, &fresh, &10, &300);
+    assert_eq!(indexed_spenders(&e, &token, &user).len(), MAX_ALLOWANCES_PAGE_SIZE + 1);
+
+    // Sorgu süresi dolanları dizinden temizler
+    assert_eq!(
+        all_allowances(&e, &token, &user),
+        vec![&e, AllowanceInfo { spender: fresh.clone(), amount: 10, expiration_ledger: 300 }]
+    );
+    assert_eq!(indexed_spenders(&e, &token, &user), vec![&e, fresh]);
+}
+
+#[test]
+fn approvals_are_not_limited_by_spender_index() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let receiver = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user, &1000);
+
+    let spender_count = 2 * MAX_ALLOWANCES_PAGE_SIZE + 5;
+    let mut spenders = std::vec::Vec::new();
+    for _ in 0..spender_count {
+        let spender = Address::generate(&e);
+        token.approve(&user, &spender, &10, &100);
+        spenders.push(spender);
+    }
+    let last = spenders.last().unwrap();
+    token.transfer_from(last, &user, &receiver, &4);
+    assert_eq!(token.allowance(&user, last), 6);
+
+    let first = token.allowances_of(&user, &0, &MAX_ALLOWANCES_PAGE_SIZE);
+    assert_eq!(first.allowances.len(), MAX_ALLOWANCES_PAGE_SIZE);
+    assert_eq!(first.next, Some(MAX_ALLOWANCES_PAGE_SIZE));
+    let tail = token.allowances_of(&user, &(2 * MAX_ALLOWANCES_PAGE_SIZE), &MAX_ALLOWANCES_PAGE_SIZE);
+    assert_eq!(tail.allowances.len(), 5);
+    assert_eq!(tail.next, None);
+    assert_eq!(
+        tail.allowances.last().unwrap(),
+        AllowanceInfo { spender: last.clone(), amount: 6, expiration_ledger: 100 }
+    );
+    assert_eq!(all_allowances(&e, &token, &user).len(), spender_count);
+}
+
+#[test]
+#[should_panic(expected = "page limit must be between 1 and 10")]
+fn allowances_of_rejects_oversized_page() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.allowances_of(&admin, &0, &(MAX_ALLOWANCES_PAGE_SIZE + 1));
+}
+
+#[test]
+fn revoke_all_allowances_invalidates_approvals() {
     let e = Env::default();
     e.mock_all_auths();
 
     let admin = Address::generate(&e);
     let user1 = Address::generate(&e);
     let user2 = Address::generate(&e);
+    let spender1 = Address::generate(&e);
+    let spender2 = Address::generate(&e);
     let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
 
-    token.mint(&user1, &1000);
-    token.freeze_account(&user1); // dondur
+    token.approve(&user1, &spender1, &100, &200);
+    token.approve(&user1, &spender2, &50, &200);
+    token.approve(&user2, &spender1, &10, &200);
 
-    token.transfer(&user1, &user2, &100); // başarısız olmalı
+    token.revoke_all_allowances(&user1);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "revoke_all_allowances"),
+                    (&user1,).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (String::from_str(&e, "revoke_all_allowances"), user1.clone()).into_val(&e));
+    assert_eq!(u32::try_from_val(&e, &data).unwrap(), 1);
+
+    assert_eq!(token.allowance(&user1, &spender1), 0);
+    assert_eq!(token.allowance(&user1, &spender2), 0);
+    assert_eq!(all_allowances(&e, &token, &user1), Vec::new(&e));
+    // Diğer hesapların izinleri etkilenmez
+    assert_eq!(token.allowance(&user2, &spender1), 10);
+
+    // İptalden sonra verilen yeni izinler geçerlidir; artırma sıfırdan başlar
+    token.increase_allowance(&user1, &spender1, &30, &200);
+    assert_eq!(token.allowance(&user1, &spender1), 30);
+    token.transfer_from(&spender1, &user1, &user2, &30);
+    assert_eq!(token.balance(&user2), 30);
 }
+
 #[test]
-fn unfreeze_account_then_transfer() {
+fn allowance_stored_before_epochs_stays_valid() {
     let e = Env::default();
     e.mock_all_auths();
 
     let admin = Address::generate(&e);
     let user1 = Address::generate(&e);
     let user2 = Address::generate(&e);
+    let spender = Address::generate(&e);
     let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
 
-    token.mint(&user1, &1000);
-    token.freeze_account(&user1);
-    token.unfreeze_account(&user1);
-    token.transfer(&user1, &user2, &100);
+    // İlk sürümün yazdığı biçimde, dönem damgası olmadan saklanan izin
+    let key = DataKey::Allowance(AllowanceDataKey { from: user1.clone(), spender: spender.clone() });
+    e.as_contract(&token.address, || {
+        e.storage().temporary().set(&key, &AllowanceValue { amount: 300, expiration_ledger: 200 });
+    });
 
-    assert_eq!(token.balance(&user2), 100);
+    assert_eq!(token.allowance(&user1, &spender), 300);
+    token.transfer_from(&spender, &user1, &user2, &100);
+    assert_eq!(token.allowance(&user1, &spender), 200);
+    // Dizinden önce verilen izin ilk harcamada listeye eklenir
+    assert_eq!(
+        all_allowances(&e, &token, &user1),
+        vec![&e, AllowanceInfo { spender: spender.clone(), amount: 200, expiration_ledger: 200 }]
+    );
+
+    token.revoke_all_allowances(&user1);
+    e.as_contract(&token.address, || {
+        e.storage().temporary().set(&key, &AllowanceValue { amount: 300, expiration_ledger: 200 });
+    });
+    assert_eq!(token.allowance(&user1, &spender), 0);
 }
+
 #[test]
-#[should_panic(expected = "account is frozen")]
-fn burn_from_frozen_account_should_fail() {
+#[should_panic(expected = "insufficient allowance")]
+fn transfer_from_after_revoke_all_allowances() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
+
+    token.approve(&user1, &spender, &100, &200);
+    token.revoke_all_allowances(&user1);
+    token.transfer_from(&spender, &user1, &user2, &1);
+}
+
+#[test]
+fn unlimited_allowance_is_not_decremented() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
+
+    token.approve(&user1, &spender, &UNLIMITED_ALLOWANCE, &200);
+    let events = e.events().all();
+    let (_, topics, data) = events.get(events.len() - 2).unwrap();
+    assert_eq!(topics, (symbol_short!("approve"), user1.clone(), spender.clone()).into_val(&e));
+    assert_eq!(<(i128, u32)>::try_from_val(&e, &data).unwrap(), (UNLIMITED_ALLOWANCE, 200));
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (String::from_str(&e, "approve_unlimited"), user1.clone(), spender.clone()).into_val(&e));
+    assert_eq!(u32::try_from_val(&e, &data).unwrap(), 200);
+
+    token.transfer_from(&spender, &user1, &user2, &300);
+    token.transfer_from(&spender, &user1, &user2, &400);
+    assert_eq!(token.balance(&user2), 700);
+    assert_eq!(token.allowance(&user1, &spender), UNLIMITED_ALLOWANCE);
+
+    // Artırma sınırsız izni taşırmaz, azaltma ise sınırlı bir izne çevirir
+    token.increase_allowance(&user1, &spender, &10, &200);
+    assert_eq!(token.allowance(&user1, &spender), UNLIMITED_ALLOWANCE);
+    token.decrease_allowance(&user1, &spender, &(UNLIMITED_ALLOWANCE - 100));
+    assert_eq!(token.allowance(&user1, &spender), 100);
+    token.transfer_from(&spender, &user1, &user2, &60);
+    assert_eq!(token.allowance(&user1, &spender), 40);
+}
+
+#[test]
+fn finite_allowance_does_not_become_unlimited() {
     let e = Env::default();
     e.mock_all_auths();
 
     let admin = Address::generate(&e);
     let user = Address::generate(&e);
+    let spender = Address::generate(&e);
     let token = create_token(&e, &admin);
 
-    // İlk olarak user1'e token mint ediyoruz
-    token.mint(&user, &1000);
-    assert_eq!(token.balance(&user), 1000);
+    token.approve(&user, &spender, &(UNLIMITED_ALLOWANCE - 10), &200);
+    assert_eq!(
+        token.try_increase_allowance(&user, &spender, &10, &200),
+        Err(Ok(TokenError::AllowanceOverflow.into()))
+    );
+    assert_eq!(token.allowance(&user, &spender), UNLIMITED_ALLOWANCE - 10);
 
-    // user1'in hesabını donduruyoruz
-    token.freeze_account(&user); // dondurma işlemi
+    // Açıkça istenen sınırsız izin kabul edilir
+    token.increase_allowance(&user, &spender, &UNLIMITED_ALLOWANCE, &200);
+    assert_eq!(token.allowance(&user, &spender), UNLIMITED_ALLOWANCE);
+}
 
-    // Dondurulmuş hesaptan token yakma işlemi deniyoruz
-    token.burn(&user, &500); // bu işlem panik yapmalı çünkü hesap dondurulmuş
+#[test]
+#[should_panic(expected = "insufficient allowance")]
+fn unlimited_allowance_expires() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
+
+    token.approve(&user1, &spender, &UNLIMITED_ALLOWANCE, &200);
+    e.ledger().set_sequence_number(201);
+    assert_eq!(token.allowance(&user1, &spender), 0);
+    token.transfer_from(&spender, &user1, &user2, &1);
+}
+
+// Kontratın permit için doğruladığı alan ayrımlı yükü imzalar
+#[allow(clippy::too_many_arguments)]
+fn sign_permit(
+    e: &Env,
+    token: &TokenClient,
+    signer: &SigningKey,
+    from: &Address,
+    spender: &Address,
+    amount: i128,
+    expiration_ledger: u32,
+    nonce: u64,
+) -> BytesN<64> {
+    let payload = (
+        token.address.clone(),
+        Symbol::new(e, "permit"),
+        e.ledger().network_id(),
+        from.clone(),
+        spender.clone(),
+        amount,
+        expiration_ledger,
+        nonce,
+    )
+        .to_xdr(e);
+    let payload: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(e, &signer.sign(&payload).to_bytes())
+}
+
+fn register_signer(e: &Env, token: &TokenClient, account: &Address, seed: u8) -> SigningKey {
+    let signer = SigningKey::from_bytes(&[seed; 32]);
+    token
+        .mock_all_auths()
+        .register_public_key(account, &BytesN::from_array(e, &signer.verifying_key().to_bytes()));
+    signer
 }
 
 #[test]
-fn test_multisig_setup() {
-    let env = Env::default();
-    let admin = Address::generate(&env);
-    let owner1 = Address::generate(&env);
-    let owner2 = Address::generate(&env);
-    let owner3 = Address::generate(&env);
-    let contract_id = env.register(Token {}, ());
-    let token = TokenClient::new(&env, &contract_id);
+fn permit_sets_allowance_from_signature() {
+    let e = Env::default();
 
-    // Initialize token
-    env.mock_auths(&[MockAuth {
-        address: &admin,
-        invoke: &MockAuthInvoke {
-            contract: &contract_id,
-            fn_name: "initialize",
-            args: (admin.clone(), 10u32, String::from_str(&env, "Test Token"), String::from_str(&env, "TEST")).into_val(&env),
-            sub_invokes: &[],
-        },
-    }]);
-    token.initialize(&admin, &10, &String::from_str(&env, "Test Token"), &String::from_str(&env, "TEST"));
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mock_all_auths().mint(&admin, &user1, &1000);
+    let signer = register_signer(&e, &token, &user1, 1);
+    assert_eq!(token.nonce(&user1), 0);
 
-    // Setup multisig
-    let owners = Vec::from_array(&env, [owner1.clone(), owner2.clone(), owner3.clone()]);
-    env.mock_auths(&[MockAuth {
-        address: &admin,
-        invoke: &MockAuthInvoke {
-            contract: &contract_id,
-            fn_name: "setup_multisig",
-            args: (owners.clone(), 2u32).into_val(&env),
-            sub_invokes: &[],
-        },
-    }]);
-    token.setup_multisig(&owners, &2);
+    // Gönderen yetkilendirme vermez; yalnızca imza doğrulanır
+    let signature = sign_permit(&e, &token, &signer, &user1, &spender, 300, 200, 0);
+    token.permit(&user1, &spender, &300, &200, &0, &signature);
+    assert_eq!(e.auths(), std::vec![]);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("approve"), user1.clone(), spender.clone()).into_val(&e));
+    assert_eq!(<(i128, u32)>::try_from_val(&e, &data).unwrap(), (300, 200));
 
-    // Verify setup
-    let stored_owners: Vec<Address> = env.as_contract(&contract_id, || {
-        env.storage().instance().get(&DataKey::MultiSigOwners).unwrap()
-    });
-    let stored_threshold: u32 = env.as_contract(&contract_id, || {
-        env.storage().instance().get(&DataKey::MultiSigThreshold).unwrap()
-    });
-    assert_eq!(stored_owners.len(), 3);
-    assert_eq!(stored_threshold, 2);
+    assert_eq!(token.allowance(&user1, &spender), 300);
+    assert_eq!(token.nonce(&user1), 1);
+    token.mock_all_auths().transfer_from(&spender, &user1, &user2, &300);
+    assert_eq!(token.balance(&user2), 300);
+
+    let signature = sign_permit(&e, &token, &signer, &user1, &spender, 50, 200, 1);
+    token.permit(&user1, &spender, &50, &200, &1, &signature);
+    assert_eq!(token.allowance(&user1, &spender), 50);
 }
 
+#[test]
+#[should_panic(expected = "invalid nonce")]
+fn permit_replay() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let signer = register_signer(&e, &token, &user, 1);
 
+    let signature = sign_permit(&e, &token, &signer, &user, &spender, 300, 200, 0);
+    token.permit(&user, &spender, &300, &200, &0, &signature);
+    token.permit(&user, &spender, &300, &200, &0, &signature);
+}
 
 #[test]
-#[should_panic(expected = "sender is not a multisig owner")]
-fn test_multisig_transaction_unauthorized() {
-    let env = Env::default();
-    let admin = Address::generate(&env);
-    let owner1 = Address::generate(&env);
-    let owner2 = Address::generate(&env);
-    let owner3 = Address::generate(&env);
-    let unauthorized = Address::generate(&env);
-    let target = Address::generate(&env);
-    let contract_id = env.register(Token {}, ());
-    let token = TokenClient::new(&env, &contract_id);
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn permit_with_tampered_amount() {
+    let e = Env::default();
 
-    // Initialize token
-    env.mock_auths(&[MockAuth {
-        address: &admin,
-        invoke: &MockAuthInvoke {
-            contract: &contract_id,
-            fn_name: "initialize",
-            args: (admin.clone(), 10u32, String::from_str(&env, "Test Token"), String::from_str(&env, "TEST")).into_val(&env),
-            sub_invokes: &[],
-        },
-    }]);
-    token.initialize(&admin, &10, &String::from_str(&env, "Test Token"), &String::from_str(&env, "TEST"));
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let signer = register_signer(&e, &token, &user, 1);
+
+    let signature = sign_permit(&e, &token, &signer, &user, &spender, 300, 200, 0);
+    token.permit(&user, &spender, &301, &200, &0, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn permit_signed_by_other_key() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    register_signer(&e, &token, &user, 1);
+
+    let other = SigningKey::from_bytes(&[2; 32]);
+    let signature = sign_permit(&e, &token, &other, &user, &spender, 300, 200, 0);
+    token.permit(&user, &spender, &300, &200, &0, &signature);
+}
+
+#[test]
+#[should_panic(expected = "public key not registered")]
+fn permit_without_registered_key() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let spender = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let signer = SigningKey::from_bytes(&[1; 32]);
+    let signature = sign_permit(&e, &token, &signer, &user, &spender, 300, 200, 0);
+    token.permit(&user, &spender, &300, &200, &0, &signature);
+}
+
+#[allow(clippy::too_many_arguments)]
+fn sign_transfer(
+    e: &Env,
+    token: &TokenClient,
+    signer: &SigningKey,
+    from: &Address,
+    to: &Address,
+    amount: i128,
+    fee: i128,
+    relayer: &Address,
+    nonce: u64,
+    deadline: u32,
+) -> BytesN<64> {
+    let payload = (
+        token.address.clone(),
+        Symbol::new(e, "transfer_with_signature"),
+        e.ledger().network_id(),
+        from.clone(),
+        to.clone(),
+        amount,
+        fee,
+        relayer.clone(),
+        nonce,
+        deadline,
+    )
+        .to_xdr(e);
+    let payload: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(e, &signer.sign(&payload).to_bytes())
+}
+
+#[test]
+fn transfer_with_signature_pays_relayer_fee() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let relayer = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mock_all_auths().mint(&admin, &user1, &1000);
+    let signer = register_signer(&e, &token, &user1, 1);
+
+    let signature = sign_transfer(&e, &token, &signer, &user1, &user2, 300, 5, &relayer, 0, 100);
+    token.transfer_with_signature(&user1, &user2, &300, &5, &relayer, &0, &100, &signature);
+    assert_eq!(e.auths(), std::vec![]);
+    let events = e.events().all();
+    let (_, topics, data) = events.get(events.len() - 2).unwrap();
+    assert_eq!(topics, (symbol_short!("transfer"), user1.clone(), user2.clone()).into_val(&e));
+    assert_eq!(i128::try_from_val(&e, &data).unwrap(), 300);
+    let (_, topics, data) = events.last().unwrap();
+    assert_eq!(topics, (symbol_short!("transfer"), user1.clone(), relayer.clone()).into_val(&e));
+    assert_eq!(i128::try_from_val(&e, &data).unwrap(), 5);
+
+    assert_eq!(token.balance(&user1), 695);
+    assert_eq!(token.balance(&user2), 300);
+    assert_eq!(token.balance(&relayer), 5);
+    assert_eq!(token.nonce(&user1), 1);
+
+    // Ücretsiz transferde relayer olayı yayınlanmaz
+    let signature = sign_transfer(&e, &token, &signer, &user1, &user2, 95, 0, &relayer, 1, 100);
+    token.transfer_with_signature(&user1, &user2, &95, &0, &relayer, &1, &100, &signature);
+    assert_eq!(token.balance(&user1), 600);
+    assert_eq!(token.balance(&relayer), 5);
+}
+
+#[test]
+#[should_panic(expected = "invalid nonce")]
+fn transfer_with_signature_replay() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let relayer = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mock_all_auths().mint(&admin, &user1, &1000);
+    let signer = register_signer(&e, &token, &user1, 1);
+
+    let signature = sign_transfer(&e, &token, &signer, &user1, &user2, 300, 5, &relayer, 0, 100);
+    token.transfer_with_signature(&user1, &user2, &300, &5, &relayer, &0, &100, &signature);
+    token.transfer_with_signature(&user1, &user2, &300, &5, &relayer, &0, &100, &signature);
+}
+
+#[test]
+#[should_panic(expected = "Error(Crypto, InvalidInput)")]
+fn transfer_with_signature_to_other_relayer() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let relayer = Address::generate(&e);
+    let other_relayer = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mock_all_auths().mint(&admin, &user1, &1000);
+    let signer = register_signer(&e, &token, &user1, 1);
+
+    let signature = sign_transfer(&e, &token, &signer, &user1, &user2, 300, 5, &relayer, 0, 100);
+    token.transfer_with_signature(&user1, &user2, &300, &5, &other_relayer, &0, &100, &signature);
+}
+
+#[test]
+#[should_panic(expected = "signature expired")]
+fn transfer_with_signature_after_deadline() {
+    let e = Env::default();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let relayer = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mock_all_auths().mint(&admin, &user1, &1000);
+    let signer = register_signer(&e, &token, &user1, 1);
+
+    let signature = sign_transfer(&e, &token, &signer, &user1, &user2, 300, 5, &relayer, 0, 100);
+    e.ledger().set_sequence_number(101);
+    token.transfer_with_signature(&user1, &user2, &300, &5, &relayer, &0, &100, &signature);
+}
+
+#[test]
+#[should_panic(expected = "allowance decreased below zero")]
+fn decrease_allowance_below_zero() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.approve(&user1, &user2, &100, &200);
+    token.decrease_allowance(&user1, &user2, &101);
+}
+
+#[test]
+fn increase_allowance_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.approve(&user1, &user2, &(i128::MAX - 1), &200);
+    assert_eq!(
+        token.try_increase_allowance(&user1, &user2, &2, &200),
+        Err(Ok(TokenError::AllowanceOverflow.into()))
+    );
+}
+
+#[test]
+#[should_panic(expected = "already initialized")]
+fn initialize_already_initialized() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.initialize(&admin, &10, &"name".into_val(&e), &"symbol".into_val(&e), &None);
+}
+
+#[test]
+#[should_panic(expected = "Decimal must fit in a u8")]
+fn decimal_is_over_max() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    e.register(
+        Token {},
+        (
+            admin,
+            u32::from(u8::MAX) + 1,
+            String::from_str(&e, "name"),
+            String::from_str(&e, "symbol"),
+            None::<i128>,
+            None::<MultiSigConfig>,
+            None::<Vec<(Address, i128)>>,
+        ),
+    );
+}
+#[test]
+#[should_panic(expected = "account is frozen")]
+fn transfer_from_frozen_account_should_fail() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &user1, &1000);
+    token.freeze_account(&admin, &user1, &1, &BytesN::from_array(&e, &[1; 32])); // dondur
+
+    token.transfer(&user1, &user2, &100); // başarısız olmalı
+}
+#[test]
+fn unfreeze_account_then_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &user1, &1000);
+    token.freeze_account(&admin, &user1, &1, &BytesN::from_array(&e, &[1; 32]));
+    token.unfreeze_account(&admin, &user1);
+    token.transfer(&user1, &user2, &100);
+
+    assert_eq!(token.balance(&user2), 100);
+}
+#[test]
+#[should_panic(expected = "account is frozen")]
+fn burn_from_frozen_account_should_fail() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // İlk olarak user1'e token mint ediyoruz
+    token.mint(&admin, &user, &1000);
+    assert_eq!(token.balance(&user), 1000);
+
+    // user1'in hesabını donduruyoruz
+    token.freeze_account(&admin, &user, &1, &BytesN::from_array(&e, &[1; 32])); // dondurma işlemi
+
+    // Dondurulmuş hesaptan token yakma işlemi deniyoruz
+    token.burn(&user, &500); // bu işlem panik yapmalı çünkü hesap dondurulmuş
+}
+
+#[test]
+fn freeze_account_stores_record() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().set_timestamp(12345);
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let case_ref = BytesN::from_array(&e, &[7; 32]);
+
+    assert_eq!(token.freeze_record(&user), None);
+
+    token.freeze_account(&admin, &user, &42, &case_ref);
+    let record = FreezeRecord {
+        reason: 42,
+        case_ref: case_ref.clone(),
+        actor: admin.clone(),
+        timestamp: 12345,
+    };
+    let (contract, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(contract, token.address);
+    assert_eq!(
+        topics,
+        (String::from_str(&e, "freeze_account"), admin.clone(), user.clone()).into_val(&e)
+    );
+    assert_eq!(FreezeRecord::try_from_val(&e, &data).unwrap(), record);
+    assert_eq!(token.freeze_record(&user), Some(record));
+
+    token.unfreeze_account(&admin, &user);
+    assert_eq!(token.freeze_record(&user), None);
+}
+
+#[test]
+fn freeze_and_unfreeze_accounts_in_bulk() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let case_ref = BytesN::from_array(&e, &[3; 32]);
+
+    let mut accounts = Vec::new(&e);
+    for _ in 0..3 {
+        accounts.push_back(Address::generate(&e));
+    }
+
+    token.freeze_accounts(&admin, &accounts, &5, &case_ref);
+    assert_eq!(e.events().all().len(), 3);
+    for account in accounts.iter() {
+        assert_eq!(token.freeze_record(&account).unwrap().reason, 5);
+    }
+
+    token.unfreeze_accounts(&admin, &accounts);
+    assert_eq!(e.events().all().len(), 3);
+    for account in accounts.iter() {
+        assert_eq!(token.freeze_record(&account), None);
+    }
+}
+
+// Son çağrının defter kaydı sayısı ağ sınırlarına sığmalı; yerel test sözleşmesinde okunmayan WASM kodu için bir kayıt eklenir
+fn assert_fits_network_limits(e: &Env) {
+    let resources = e.cost_estimate().resources();
+    assert!(resources.write_entries <= TX_MAX_WRITE_LEDGER_ENTRIES, "{:?}", resources);
+    assert!(resources.read_entries + resources.write_entries < TX_MAX_READ_LEDGER_ENTRIES, "{:?}", resources);
+}
+
+#[test]
+fn freeze_accounts_max_batch_fits_budget() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let case_ref = BytesN::from_array(&e, &[3; 32]);
+
+    let mut accounts = Vec::new(&e);
+    for _ in 0..MAX_BATCH_SIZE {
+        accounts.push_back(Address::generate(&e));
+    }
+
+    // Varsayılan ağ bütçesi aşılırsa çağrı panik yapar
+    e.cost_estimate().budget().reset_default();
+    token.freeze_accounts(&admin, &accounts, &1, &case_ref);
+    assert_fits_network_limits(&e);
+    e.cost_estimate().budget().reset_default();
+    token.unfreeze_accounts(&admin, &accounts);
+    assert_fits_network_limits(&e);
+}
+
+#[test]
+#[should_panic(expected = "batch size must be between 1 and 20")]
+fn freeze_accounts_over_batch_limit() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let mut accounts = Vec::new(&e);
+    for _ in 0..=MAX_BATCH_SIZE {
+        accounts.push_back(Address::generate(&e));
+    }
+    token.freeze_accounts(&admin, &accounts, &1, &BytesN::from_array(&e, &[3; 32]));
+}
+
+#[test]
+fn batch_transfer_to_many_recipients() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
+
+    let transfers = vec![&e, (user2.clone(), 100_i128), (user3.clone(), 250_i128), (user2.clone(), 50_i128)];
+    token.batch_transfer(&user1, &transfers);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            user1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "batch_transfer"),
+                    (&user1, transfers.clone()).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    let events = e.events().all();
+    assert_eq!(events.len(), 3);
+    for (i, (to, amount)) in transfers.iter().enumerate() {
+        let (_, topics, data) = events.get(i as u32).unwrap();
+        assert_eq!(topics, (symbol_short!("transfer"), user1.clone(), to).into_val(&e));
+        assert_eq!(i128::try_from_val(&e, &data).unwrap(), amount);
+    }
+
+    assert_eq!(token.balance(&user1), 600);
+    assert_eq!(token.balance(&user2), 150);
+    assert_eq!(token.balance(&user3), 250);
+}
+
+#[test]
+fn batch_transfer_is_all_or_nothing() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
+
+    // Son transfer bakiyeyi aşar; önceki transferler de geri alınır
+    let transfers = vec![&e, (user2.clone(), 600_i128), (user3.clone(), 401_i128)];
+    assert!(token.try_batch_transfer(&user1, &transfers).is_err());
+    assert_eq!(token.balance(&user1), 1000);
+    assert_eq!(token.balance(&user2), 0);
+
+    let transfers = vec![&e, (user2.clone(), 600_i128), (user3.clone(), -1_i128)];
+    assert!(token.try_batch_transfer(&user1, &transfers).is_err());
+    assert_eq!(token.balance(&user1), 1000);
+    assert_eq!(token.balance(&user2), 0);
+}
+
+#[test]
+#[should_panic(expected = "account is frozen")]
+fn batch_transfer_from_frozen_account_should_fail() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
+    token.freeze_account(&admin, &user1, &1, &BytesN::from_array(&e, &[1; 32]));
+
+    token.batch_transfer(&user1, &vec![&e, (user2.clone(), 100_i128)]);
+}
+
+#[test]
+fn batch_transfer_max_batch_fits_budget() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user, &(MAX_BATCH_SIZE as i128));
+
+    let mut transfers = Vec::new(&e);
+    for _ in 0..MAX_BATCH_SIZE {
+        transfers.push_back((Address::generate(&e), 1_i128));
+    }
+
+    e.cost_estimate().budget().reset_default();
+    token.batch_transfer(&user, &transfers);
+
+    // Her alıcı bir bakiye kaydı yazar; ayrıca gönderici bakiyesi ve auth nonce'u yazılır
+    assert_eq!(e.cost_estimate().resources().write_entries, MAX_BATCH_SIZE + 2);
+    assert_fits_network_limits(&e);
+    assert_eq!(token.balance(&user), 0);
+}
+
+#[test]
+#[should_panic(expected = "batch size must be between 1 and 20")]
+fn batch_transfer_over_batch_limit() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    let mut transfers = Vec::new(&e);
+    for _ in 0..=MAX_BATCH_SIZE {
+        transfers.push_back((Address::generate(&e), 1_i128));
+    }
+    token.batch_transfer(&user, &transfers);
+}
+
+#[test]
+fn pause_blocks_balance_changes_but_not_reads() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &user1, &1000);
+    token.approve(&user1, &user2, &500, &200);
+    token.grant_role(&admin, &Role::Pauser, &guardian);
+
+    token.pause(&guardian);
+    assert!(token.is_paused());
+    assert_eq!(token.balance(&user1), 1000);
+    assert_eq!(token.allowance(&user1, &user2), 500);
+    assert_eq!(token.decimals(), 7);
+
+    assert!(token.try_transfer(&user1, &user2, &1).is_err());
+    assert!(token.try_transfer_from(&user2, &user1, &user2, &1).is_err());
+    assert!(token.try_burn(&user1, &1).is_err());
+    assert!(token.try_burn_from(&user2, &user1, &1).is_err());
+    assert!(token.try_mint(&admin, &user1, &1).is_err());
+
+    token.unpause();
+    assert!(!token.is_paused());
+    token.transfer(&user1, &user2, &100);
+    assert_eq!(token.balance(&user2), 100);
+}
+
+#[test]
+#[should_panic(expected = "caller does not have the required role")]
+fn pause_by_stranger_should_fail() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.pause(&stranger);
+}
+
+#[test]
+fn unpause_requires_admin() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let guardian = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    e.mock_all_auths();
+    token.grant_role(&admin, &Role::Pauser, &guardian);
+    token.pause(&guardian);
+
+    // Duraklatma yetkilisi duraklatmayı kaldıramaz
+    e.mock_auths(&[MockAuth {
+        address: &guardian,
+        invoke: &MockAuthInvoke {
+            contract: &token.address,
+            fn_name: "unpause",
+            args: ().into_val(&e),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(token.try_unpause().is_err());
+    assert!(token.is_paused());
+}
+
+#[test]
+fn unpause_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+
+    token.pause(&admin);
+    let tx_id = token.propose_multisig_transaction(&owner, &Symbol::new(&e, "unpause"), &admin, &0, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+    assert!(!token.is_paused());
+}
+
+#[test]
+fn paused_functions_block_only_flagged_entry_points() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &user1, &1000);
+    token.approve(&user1, &user2, &500, &200);
+
+    token.set_paused_functions(&(PAUSE_MINT | PAUSE_TRANSFER_FROM));
+    let (_, _, data) = e.events().all().last().unwrap();
+    assert_eq!(<(u32, u32)>::try_from_val(&e, &data).unwrap(), (0, PAUSE_MINT | PAUSE_TRANSFER_FROM));
+    assert_eq!(token.paused_functions(), PAUSE_MINT | PAUSE_TRANSFER_FROM);
+
+    assert!(token.try_mint(&admin, &user1, &1).is_err());
+    assert!(token.try_transfer_from(&user2, &user1, &user2, &1).is_err());
+    token.transfer(&user1, &user2, &100);
+    token.burn(&user1, &100);
+    assert_eq!(token.balance(&user1), 800);
+
+    token.set_paused_functions(&0);
+    token.mint(&admin, &user1, &1);
+    assert_eq!(token.balance(&user1), 801);
+}
+
+#[test]
+#[should_panic(expected = "unknown pause flag")]
+fn set_paused_functions_rejects_unknown_flags() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.set_paused_functions(&(1 << 31));
+}
+
+#[test]
+fn set_paused_functions_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+
+    let tx_id = token.propose_multisig_transaction(
+        &owner,
+        &Symbol::new(&e, "set_paused_functions"),
+        &admin,
+        &i128::from(PAUSE_MINT),
+        &1000,
+    );
+    token.approve_multisig_transaction(&owner, &tx_id);
+    assert_eq!(token.paused_functions(), PAUSE_MINT);
+}
+
+#[test]
+fn clawback_from_frozen_account() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &user, &1000);
+    token.freeze_account(&admin, &user, &1, &BytesN::from_array(&e, &[1; 32]));
+
+    token.clawback(&admin, &user, &400);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    symbol_short!("clawback"),
+                    (&admin, &user, 400_i128).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    let (_, topics, _) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("clawback"), admin.clone(), user.clone()).into_val(&e));
+    assert_eq!(token.balance(&user), 600);
+}
+
+#[test]
+fn force_transfer_from_frozen_account() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.mint(&admin, &user1, &1000);
+    token.freeze_account(&admin, &user1, &1, &BytesN::from_array(&e, &[1; 32]));
+
+    token.force_transfer(&admin, &user1, &user2, &250);
+    let events = e.events().all();
+    let (_, topics, data) = events.get(events.len() - 2).unwrap();
+    assert_eq!(topics, (symbol_short!("transfer"), user1.clone(), user2.clone()).into_val(&e));
+    assert_eq!(i128::try_from_val(&e, &data).unwrap(), 250);
+    let (_, topics, _) = events.last().unwrap();
+    assert_eq!(
+        topics,
+        (String::from_str(&e, "force_transfer"), admin.clone(), user1.clone(), user2.clone()).into_val(&e)
+    );
+    assert_eq!(token.balance(&user1), 750);
+    assert_eq!(token.balance(&user2), 250);
+}
+
+#[test]
+fn clawback_and_force_transfer_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+
+    token.mint(&admin, &user1, &1000);
+
+    let tx_id = token.propose_multisig_transaction(&owner, &symbol_short!("clawback"), &user1, &100, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+    assert_eq!(token.balance(&user1), 900);
+
+    let tx_id = token.propose_force_transfer(&owner, &user1, &user2, &300, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+    assert_eq!(token.balance(&user1), 600);
+    assert_eq!(token.balance(&user2), 300);
+}
+
+#[test]
+fn clawback_and_force_transfer_respect_pause() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &1000);
+
+    token.pause(&admin);
+    assert!(token.try_clawback(&admin, &user1, &100).is_err());
+    assert!(token.try_force_transfer(&admin, &user1, &user2, &100).is_err());
+    token.unpause();
+
+    token.set_paused_functions(&PAUSE_BURN);
+    assert!(token.try_clawback(&admin, &user1, &100).is_err());
+    token.force_transfer(&admin, &user1, &user2, &100);
+
+    token.set_paused_functions(&PAUSE_TRANSFER);
+    assert!(token.try_force_transfer(&admin, &user1, &user2, &100).is_err());
+    token.clawback(&admin, &user1, &100);
+    assert_eq!(token.balance(&user1), 800);
+    assert_eq!(token.balance(&user2), 100);
+}
+
+#[test]
+fn clawback_and_force_transfer_through_multisig_respect_pause() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+    token.mint(&admin, &user1, &1000);
+
+    let clawback_id = token.propose_multisig_transaction(&owner, &symbol_short!("clawback"), &user1, &100, &1000);
+    let force_id = token.propose_force_transfer(&owner, &user1, &user2, &300, &1000);
+
+    token.set_paused_functions(&PAUSE_MULTISIG_EXECUTE);
+    assert!(token.try_approve_multisig_transaction(&owner, &clawback_id).is_err());
+    assert!(token.try_approve_multisig_transaction(&owner, &force_id).is_err());
+
+    token.set_paused_functions(&0);
+    token.pause(&admin);
+    assert!(token.try_approve_multisig_transaction(&owner, &clawback_id).is_err());
+    assert!(token.try_approve_multisig_transaction(&owner, &force_id).is_err());
+    assert_eq!(token.balance(&user1), 1000);
+
+    token.unpause();
+    token.approve_multisig_transaction(&owner, &clawback_id);
+    token.approve_multisig_transaction(&owner, &force_id);
+    assert_eq!(token.balance(&user1), 600);
+    assert_eq!(token.balance(&user2), 300);
+}
+
+#[test]
+fn role_holders_use_privileged_entry_points() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let freezer = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.grant_role(&admin, &Role::Minter, &minter);
+    token.grant_role(&admin, &Role::Freezer, &freezer);
+    assert!(token.has_role(&Role::Minter, &minter));
+    assert!(!token.has_role(&Role::Freezer, &minter));
+
+    token.mint(&minter, &user, &100);
+    assert_eq!(token.balance(&user), 100);
+    assert!(token.try_freeze_account(&minter, &user, &1, &BytesN::from_array(&e, &[1; 32])).is_err());
+
+    token.freeze_account(&freezer, &user, &1, &BytesN::from_array(&e, &[1; 32]));
+    assert_eq!(token.freeze_record(&user).unwrap().actor, freezer);
+
+    token.revoke_role(&admin, &Role::Minter, &minter);
+    assert!(!token.has_role(&Role::Minter, &minter));
+    assert!(token.try_mint(&minter, &user, &100).is_err());
+
+    token.renounce_role(&freezer, &Role::Freezer);
+    assert!(!token.has_role(&Role::Freezer, &freezer));
+}
+
+#[test]
+fn role_admin_can_grant_and_revoke() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let manager = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    assert!(token.try_grant_role(&manager, &Role::Minter, &minter).is_err());
+
+    token.set_role_admin(&Role::Minter, &Some(Role::MultisigManager));
+    assert_eq!(token.role_admin(&Role::Minter), Some(Role::MultisigManager));
+    token.grant_role(&admin, &Role::MultisigManager, &manager);
+
+    token.grant_role(&manager, &Role::Minter, &minter);
+    assert!(token.has_role(&Role::Minter, &minter));
+    token.revoke_role(&manager, &Role::Minter, &minter);
+    assert!(!token.has_role(&Role::Minter, &minter));
+
+    // Yönetici rolü yalnızca kendi rolünü yönetebilir
+    assert!(token.try_grant_role(&manager, &Role::Freezer, &minter).is_err());
+}
+
+#[test]
+fn admin_transfer_requires_acceptance() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.propose_admin(&new_admin, &Some(100));
+    assert_eq!(
+        token.pending_admin(),
+        Some(PendingAdmin {
+            new_admin: new_admin.clone(),
+            expiration_ledger: Some(100),
+        })
+    );
+
+    // Kabul edilene kadar eski yönetici yetkili kalır
+    token.mint(&admin, &user, &10);
+    assert!(token.try_mint(&new_admin, &user, &10).is_err());
+
+    token.accept_admin();
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            new_admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "accept_admin"),
+                    ().into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.pending_admin(), None);
+
+    token.mint(&new_admin, &user, &10);
+    assert!(token.try_mint(&admin, &user, &10).is_err());
+    assert_eq!(token.balance(&user), 20);
+}
+
+#[test]
+#[should_panic(expected = "admin transfer expired")]
+fn accept_admin_after_expiration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.propose_admin(&new_admin, &Some(100));
+    e.ledger().set_sequence_number(101);
+    token.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "no pending admin transfer")]
+fn cancel_admin_transfer_clears_pending() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.set_admin(&new_admin);
+    assert_eq!(token.pending_admin().unwrap().expiration_ledger, None);
+
+    token.cancel_admin_transfer();
+    assert_eq!(token.pending_admin(), None);
+    token.accept_admin();
+}
+
+#[test]
+fn renounce_admin_disables_privileged_operations() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.grant_role(&admin, &Role::Minter, &minter);
+    token.mint(&admin, &user1, &1000);
+    token.renounce_admin();
+    assert!(token.is_admin_renounced());
+
+    assert!(token.try_mint(&admin, &user1, &1).is_err());
+    assert!(token.try_mint(&minter, &user1, &1).is_err());
+    assert!(token.try_freeze_account(&admin, &user1, &1, &BytesN::from_array(&e, &[1; 32])).is_err());
+    assert!(token.try_propose_admin(&admin, &None).is_err());
+
+    // Standart token işlemleri çalışmaya devam eder
+    token.transfer(&user1, &user2, &100);
+    assert_eq!(token.balance(&user2), 100);
+}
+
+#[test]
+fn renounce_admin_while_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.pause(&admin);
+    assert!(token.try_renounce_admin().is_err());
+    token.unpause();
+
+    token.set_paused_functions(&PAUSE_TRANSFER_FROM);
+    assert!(token.try_renounce_admin().is_err());
+    token.set_paused_functions(&0);
+
+    token.renounce_admin();
+    assert!(token.is_admin_renounced());
+}
+
+#[test]
+#[should_panic(expected = "already initialized")]
+fn initialize_after_renounce_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.renounce_admin();
+    token.initialize(&admin, &7, &"name".into_val(&e), &"symbol".into_val(&e), &None);
+}
+
+#[test]
+fn minter_mints_within_quota_per_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.set_minter(&minter, &500, &100);
+    assert_eq!(token.minter_remaining(&minter), 500);
+
+    token.mint(&minter, &user, &300);
+    assert_eq!(token.minter_allowance(&minter).unwrap().used, 300);
+    assert_eq!(token.minter_remaining(&minter), 200);
+    assert!(token.try_mint(&minter, &user, &201).is_err());
+
+    // Basımın üzerinden bir dönem geçince kota yenilenir
+    e.ledger().set_sequence_number(99);
+    assert_eq!(token.minter_remaining(&minter), 200);
+    e.ledger().set_sequence_number(100);
+    assert_eq!(token.minter_remaining(&minter), 500);
+    token.mint(&minter, &user, &500);
+    assert_eq!(token.balance(&user), 800);
+
+    // Yönetici kotadan bağımsız basabilir
+    token.mint(&admin, &user, &1000);
+    assert_eq!(token.balance(&user), 1800);
+
+    token.remove_minter(&minter);
+    assert_eq!(token.minter_allowance(&minter), None);
+    assert!(token.try_mint(&minter, &user, &1).is_err());
+}
+
+#[test]
+fn minter_quota_uses_rolling_window() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.set_minter(&minter, &500, &100);
+
+    // Sabit pencere sınırında art arda iki ledger'da tavanın iki katı basılamaz
+    e.ledger().set_sequence_number(198);
+    token.mint(&minter, &user, &500);
+    e.ledger().set_sequence_number(199);
+    assert!(token.try_mint(&minter, &user, &500).is_err());
+    e.ledger().set_sequence_number(297);
+    assert!(token.try_mint(&minter, &user, &1).is_err());
+    e.ledger().set_sequence_number(298);
+    token.mint(&minter, &user, &500);
+
+    // Pencere içindeki basımlar tek tek düşer
+    e.ledger().set_sequence_number(400);
+    token.mint(&minter, &user, &100);
+    e.ledger().set_sequence_number(450);
+    token.mint(&minter, &user, &300);
+    assert_eq!(token.minter_remaining(&minter), 100);
+    e.ledger().set_sequence_number(500);
+    assert_eq!(token.minter_remaining(&minter), 200);
+    e.ledger().set_sequence_number(550);
+    assert_eq!(token.minter_remaining(&minter), 500);
+    assert_eq!(token.balance(&user), 1400);
+}
+
+#[test]
+fn minter_remaining_after_cap_lowered() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.set_minter(&minter, &500, &100);
+    token.mint(&minter, &user, &400);
+    token.set_minter(&minter, &200, &100);
+    assert_eq!(token.minter_allowance(&minter).unwrap().used, 400);
+    assert_eq!(token.minter_remaining(&minter), 0);
+    assert!(token.try_mint(&minter, &user, &1).is_err());
+}
+
+#[test]
+fn minter_buckets_stay_bounded() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.set_minter(&minter, &10_000, &160);
+
+    for sequence in 0..400 {
+        e.ledger().set_sequence_number(sequence);
+        token.mint(&minter, &user, &1);
+    }
+    let allowance = token.minter_allowance(&minter).unwrap();
+    assert!(allowance.buckets.len() <= 17);
+    assert!(allowance.used >= 160);
+    assert_eq!(token.balance(&user), 400);
+}
+
+#[test]
+#[should_panic(expected = "minter quota exceeded")]
+fn minter_quota_exceeded() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    // Kotası olan Minter rolü sahibi de kotaya tabidir
+    token.grant_role(&admin, &Role::Minter, &minter);
+    token.set_minter(&minter, &100, &100);
+    token.mint(&minter, &user, &101);
+}
+
+#[test]
+fn total_supply_tracks_mint_and_burn() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+
+    assert_eq!(token.total_supply(), 0);
+    assert_eq!(token.max_supply(), None);
+
+    token.mint(&admin, &user1, &1000);
+    token.burn(&user1, &100);
+    token.approve(&user1, &user2, &100, &200);
+    token.burn_from(&user2, &user1, &50);
+    token.transfer(&user1, &user2, &100);
+    assert_eq!(token.total_supply(), 850);
+
+    let tx_id = token.propose_multisig_transaction(&owner, &symbol_short!("mint"), &user2, &300, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+    let tx_id = token.propose_multisig_transaction(&owner, &symbol_short!("burn"), &user2, &200, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+    assert_eq!(token.total_supply(), 950);
+
+    token.clawback(&admin, &user1, &50);
+    assert_eq!(token.total_supply(), 900);
+}
+
+#[test]
+#[should_panic(expected = "max supply exceeded")]
+fn mint_over_max_supply() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token_with(&e, &admin, Some(1000), None, None);
+    assert_eq!(token.max_supply(), Some(1000));
+
+    token.mint(&admin, &user, &600);
+    token.burn(&user, &100);
+    token.mint(&admin, &user, &500);
+    assert_eq!(token.total_supply(), 1000);
+
+    token.mint(&admin, &user, &1);
+}
+
+// Taşma sınırlarına yakın değerler
+const BOUNDARY_VALUES: [i128; 6] = [0, 1, i128::MAX / 2, i128::MAX / 2 + 1, i128::MAX - 1, i128::MAX];
+
+#[test]
+fn mint_near_i128_max_returns_supply_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    for a in BOUNDARY_VALUES {
+        for b in BOUNDARY_VALUES {
+            let user = Address::generate(&e);
+            token.mint(&admin, &user, &a);
+
+            let result = token.try_mint(&admin, &user, &b);
+            match a.checked_add(b) {
+                Some(sum) => {
+                    assert!(result.is_ok());
+                    assert_eq!(token.balance(&user), sum);
+                    assert_eq!(token.total_supply(), sum);
+                }
+                None => {
+                    assert_eq!(result, Err(Ok(TokenError::SupplyOverflow.into())));
+                    assert_eq!(token.balance(&user), a);
+                    assert_eq!(token.total_supply(), a);
+                }
+            }
+
+            // Sonraki çift için arzı sıfırla
+            token.burn(&user, &token.balance(&user));
+        }
+    }
+}
+
+#[test]
+fn transfer_near_i128_max_returns_balance_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    for a in BOUNDARY_VALUES {
+        for b in BOUNDARY_VALUES {
+            let from = Address::generate(&e);
+            let to = Address::generate(&e);
+
+            // Arz takibinden bağımsız olarak bakiyeleri doğrudan yaz
+            e.as_contract(&token.address, || {
+                e.storage().persistent().set(&DataKey::Balance(from.clone()), &b);
+                e.storage().persistent().set(&DataKey::Balance(to.clone()), &a);
+            });
+
+            let result = token.try_transfer(&from, &to, &b);
+            match a.checked_add(b) {
+                Some(sum) => {
+                    assert!(result.is_ok());
+                    assert_eq!(token.balance(&to), sum);
+                    assert_eq!(token.balance(&from), 0);
+                }
+                None => {
+                    assert_eq!(result, Err(Ok(TokenError::BalanceOverflow.into())));
+                    assert_eq!(token.balance(&to), a);
+                    assert_eq!(token.balance(&from), b);
+                }
+            }
+        }
+    }
+}
+
+// Özellik testlerinde her durum yeni bir Env kullanır; anlık görüntü dosyası üretilmez
+fn property_env() -> Env {
+    let e = Env::new_with_config(EnvTestConfig {
+        capture_snapshot_at_drop: false,
+    });
+    e.mock_all_auths();
+    e
+}
+
+// Taşma sınırlarının çevresinde yoğunlaşan negatif olmayan miktarlar
+fn amount_near_max() -> impl Strategy<Value = i128> {
+    prop_oneof![
+        0..=i128::MAX,
+        (i128::MAX / 2 - 1_000)..=(i128::MAX / 2 + 1_000),
+        (i128::MAX - 1_000)..=i128::MAX,
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn mint_overflow_property(a in amount_near_max(), b in amount_near_max()) {
+        let e = property_env();
+        let admin = Address::generate(&e);
+        let user = Address::generate(&e);
+        let token = create_token(&e, &admin);
+
+        token.mint(&admin, &user, &a);
+        let result = token.try_mint(&admin, &user, &b);
+        match a.checked_add(b) {
+            Some(sum) => {
+                prop_assert!(result.is_ok());
+                prop_assert_eq!(token.balance(&user), sum);
+                prop_assert_eq!(token.total_supply(), sum);
+            }
+            None => {
+                prop_assert_eq!(result, Err(Ok(TokenError::SupplyOverflow.into())));
+                prop_assert_eq!(token.balance(&user), a);
+                prop_assert_eq!(token.total_supply(), a);
+            }
+        }
+    }
+
+    #[test]
+    fn transfer_overflow_property(a in amount_near_max(), b in amount_near_max()) {
+        let e = property_env();
+        let admin = Address::generate(&e);
+        let from = Address::generate(&e);
+        let to = Address::generate(&e);
+        let token = create_token(&e, &admin);
+
+        e.as_contract(&token.address, || {
+            e.storage().persistent().set(&DataKey::Balance(from.clone()), &b);
+            e.storage().persistent().set(&DataKey::Balance(to.clone()), &a);
+        });
+
+        let result = token.try_transfer(&from, &to, &b);
+        match a.checked_add(b) {
+            Some(sum) => {
+                prop_assert!(result.is_ok());
+                prop_assert_eq!(token.balance(&to), sum);
+                prop_assert_eq!(token.balance(&from), 0);
+            }
+            None => {
+                prop_assert_eq!(result, Err(Ok(TokenError::BalanceOverflow.into())));
+                prop_assert_eq!(token.balance(&to), a);
+                prop_assert_eq!(token.balance(&from), b);
+            }
+        }
+    }
+
+    #[test]
+    fn allowance_overflow_property(a in amount_near_max(), b in amount_near_max()) {
+        let e = property_env();
+        let admin = Address::generate(&e);
+        let from = Address::generate(&e);
+        let spender = Address::generate(&e);
+        let token = create_token(&e, &admin);
+
+        token.approve(&from, &spender, &a, &200);
+        let result = token.try_increase_allowance(&from, &spender, &b, &200);
+        // Sınırsız izin yalnızca açıkça istenirse oluşur; sentinel değere ulaşan toplam taşmadır
+        let expected = if a == UNLIMITED_ALLOWANCE || b == UNLIMITED_ALLOWANCE {
+            Some(UNLIMITED_ALLOWANCE)
+        } else {
+            a.checked_add(b).filter(|sum| *sum != UNLIMITED_ALLOWANCE)
+        };
+        match expected {
+            Some(sum) => {
+                prop_assert!(result.is_ok());
+                prop_assert_eq!(token.allowance(&from, &spender), sum);
+            }
+            None => {
+                prop_assert_eq!(result, Err(Ok(TokenError::AllowanceOverflow.into())));
+                prop_assert_eq!(token.allowance(&from, &spender), a);
+            }
+        }
+    }
+}
+
+#[test]
+fn constructor_sets_up_initial_multisig() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let owners = Vec::from_array(&e, [Address::generate(&e), Address::generate(&e)]);
+    let token = create_token_with(
+        &e,
+        &admin,
+        Some(5000),
+        Some(MultiSigConfig {
+            owners: owners.clone(),
+            threshold: 2,
+        }),
+        None,
+    );
+
+    assert_eq!(token.decimals(), 7);
+    assert_eq!(token.name(), String::from_str(&e, "name"));
+    assert_eq!(token.max_supply(), Some(5000));
+    e.as_contract(&token.address, || {
+        let stored_owners: Vec<Address> = e.storage().instance().get(&DataKey::MultiSigOwners).unwrap();
+        let stored_threshold: u32 = e.storage().instance().get(&DataKey::MultiSigThreshold).unwrap();
+        assert_eq!(stored_owners, owners);
+        assert_eq!(stored_threshold, 2);
+    });
+}
+
+#[test]
+#[should_panic(expected = "constructor invocation has failed")]
+fn constructor_rejects_invalid_multisig_threshold() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    create_token_with(
+        &e,
+        &admin,
+        None,
+        Some(MultiSigConfig {
+            owners: Vec::from_array(&e, [Address::generate(&e)]),
+            threshold: 2,
+        }),
+        None,
+    );
+}
+
+#[test]
+fn constructor_mints_genesis_allocations() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let allocations = vec![&e, (user1.clone(), 700_i128), (user2.clone(), 300_i128)];
+    let token = create_token_with(&e, &admin, Some(1000), None, Some(allocations));
+
+    let mint_events = e
+        .events()
+        .all()
+        .iter()
+        .filter(|(_, topics, _)| topics.get(0).unwrap().shallow_eq(&symbol_short!("mint").to_val()))
+        .count();
+    assert_eq!(mint_events, 2);
+
+    assert_eq!(token.balance(&user1), 700);
+    assert_eq!(token.balance(&user2), 300);
+    assert_eq!(token.total_supply(), 1000);
+}
+
+#[test]
+#[should_panic(expected = "max supply exceeded")]
+fn genesis_allocations_over_max_supply() {
+    let e = Env::default();
+    let admin = Address::generate(&e);
+    let allocations = vec![&e, (Address::generate(&e), 600_i128), (Address::generate(&e), 500_i128)];
+    create_token_with(&e, &admin, Some(1000), None, Some(allocations));
+}
+
+#[test]
+fn update_metadata_keeps_decimal_and_history() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    for i in 0..7u32 {
+        e.ledger().set_sequence_number(i);
+        token.update_metadata(&String::from_str(&e, "Fixed Name"), &String::from_str(&e, "FIX"));
+    }
+    assert_eq!(token.name(), String::from_str(&e, "Fixed Name"));
+    assert_eq!(token.symbol(), String::from_str(&e, "FIX"));
+    assert_eq!(token.decimals(), 7);
+
+    // Yalnızca son beş önceki değer tutulur
+    let history = token.metadata_history();
+    assert_eq!(history.len(), 5);
+    assert_eq!(history.get(0).unwrap().replaced_ledger, 2);
+    assert_eq!(history.get(4).unwrap().replaced_ledger, 6);
+
+    token.update_metadata(&String::from_str(&e, "Other"), &String::from_str(&e, "OTH"));
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (String::from_str(&e, "update_metadata"), admin.clone()).into_val(&e));
+    assert_eq!(
+        <(String, String)>::try_from_val(&e, &data).unwrap(),
+        (String::from_str(&e, "Other"), String::from_str(&e, "OTH"))
+    );
+}
+
+#[test]
+fn update_metadata_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+
+    let tx_id = token.propose_update_metadata(&owner, &String::from_str(&e, "New Name"), &String::from_str(&e, "NEW"), &1000);
+    assert_eq!(token.name(), String::from_str(&e, "name"));
+    token.approve_multisig_transaction(&owner, &tx_id);
+
+    assert_eq!(token.name(), String::from_str(&e, "New Name"));
+    assert_eq!(token.symbol(), String::from_str(&e, "NEW"));
+    let history = token.metadata_history();
+    assert_eq!(history.len(), 1);
+    assert_eq!(history.get(0).unwrap().name, String::from_str(&e, "name"));
+    assert_eq!(history.get(0).unwrap().symbol, String::from_str(&e, "symbol"));
+}
+
+fn sample_token_info(e: &Env) -> TokenInfo {
+    TokenInfo {
+        logo_uri: String::from_str(e, "https://metasigchain.example/logo.png"),
+        home_domain: String::from_str(e, "metasigchain.example"),
+        description: String::from_str(e, "Multi-signature governed token"),
+        issuer_contact: String::from_str(e, "issuer@metasigchain.example"),
+    }
+}
+
+#[test]
+fn set_token_info_by_admin() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    assert_eq!(token.token_info(), None);
+
+    let info = sample_token_info(&e);
+    token.set_token_info(&info);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            admin.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "set_token_info"),
+                    (info.clone(),).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+    assert_eq!(token.token_info(), Some(info));
+}
+
+#[test]
+fn set_token_info_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+
+    let info = sample_token_info(&e);
+    let tx_id = token.propose_set_token_info(&owner, &info, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+    assert_eq!(token.token_info(), Some(info));
+}
+
+#[test]
+fn token_info_is_bounded_and_kept_out_of_instance_storage() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = Address::generate(&e);
+
+    let mut info = sample_token_info(&e);
+    let limit = "x".repeat(MAX_TOKEN_INFO_FIELD_LEN as usize);
+    info.description = String::from_str(&e, &limit);
+    token.set_token_info(&info);
+    let stored = info.clone();
+    assert_eq!(token.token_info(), Some(stored.clone()));
+    e.as_contract(&token.address, || {
+        assert!(!e.storage().instance().has(&DataKey::TokenInfo));
+        assert!(e.storage().persistent().has(&DataKey::TokenInfo));
+    });
+
+    let oversized = limit + "x";
+    info.description = String::from_str(&e, &oversized);
+    assert!(token.try_set_token_info(&info).is_err());
+
+    token.setup_multisig(&admin, &vec![&e, owner.clone()], &1);
+    assert!(token.try_propose_set_token_info(&owner, &info, &1000).is_err());
+    assert_eq!(token.token_info(), Some(stored));
+}
+
+// soroban-sdk test_add_u64 sözleşmesinin derlenmiş hali; yükseltme hedefi olarak kullanılır
+const UPGRADE_WASM: &[u8] = include_bytes!("../test_wasms/add_u64.wasm");
+
+#[test]
+fn upgrade_by_admin_when_multisig_disabled() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let new_wasm_hash = e.deployer().upload_contract_wasm(Bytes::from_slice(&e, UPGRADE_WASM));
+    assert_eq!(token.wasm_hash(), None);
+
+    token.upgrade(&new_wasm_hash);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (String::from_str(&e, "upgrade"), admin.clone()).into_val(&e));
+    assert_eq!(
+        <(Option<BytesN<32>>, BytesN<32>)>::try_from_val(&e, &data).unwrap(),
+        (None, new_wasm_hash.clone())
+    );
+
+    // Kontrat artık yeni kodu çalıştırır
+    let sum: u64 = e.invoke_contract(&token.address, &symbol_short!("add"), vec![&e, 2_u64.into_val(&e), 3_u64.into_val(&e)]);
+    assert_eq!(sum, 5);
+    let stored_hash: Option<BytesN<32>> = e.as_contract(&token.address, || {
+        e.storage().instance().get(&DataKey::WasmHash)
+    });
+    assert_eq!(stored_hash, Some(new_wasm_hash));
+}
+
+#[test]
+#[should_panic(expected = "upgrade requires multisig approval")]
+fn upgrade_by_admin_when_multisig_enabled() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    setup_single_owner_multisig(&e, &token, &admin);
+
+    let new_wasm_hash = e.deployer().upload_contract_wasm(Bytes::from_slice(&e, UPGRADE_WASM));
+    token.upgrade(&new_wasm_hash);
+}
+
+#[test]
+fn upgrade_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+
+    let new_wasm_hash = e.deployer().upload_contract_wasm(Bytes::from_slice(&e, UPGRADE_WASM));
+    let tx_id = token.propose_upgrade(&owner, &new_wasm_hash, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+
+    let sum: u64 = e.invoke_contract(&token.address, &symbol_short!("add"), vec![&e, 2_u64.into_val(&e), 3_u64.into_val(&e)]);
+    assert_eq!(sum, 5);
+}
+
+#[test]
+fn upgrade_requires_threshold_of_distinct_owner_approvals() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let owner1 = Address::generate(&e);
+    let owner2 = Address::generate(&e);
+    let owner3 = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.setup_multisig(&admin, &vec![&e, owner1.clone(), owner2.clone(), owner3.clone()], &2);
+
+    let new_wasm_hash = e.deployer().upload_contract_wasm(Bytes::from_slice(&e, UPGRADE_WASM));
+    let tx_id = token.propose_upgrade(&owner1, &new_wasm_hash, &1000);
+    assert_eq!(
+        e.auths(),
+        std::vec![(
+            owner1.clone(),
+            AuthorizedInvocation {
+                function: AuthorizedFunction::Contract((
+                    token.address.clone(),
+                    Symbol::new(&e, "propose_upgrade"),
+                    (&owner1, new_wasm_hash.clone(), 1000_u64).into_val(&e),
+                )),
+                sub_invocations: std::vec![]
+            }
+        )]
+    );
+
+    // Aynı sahibin ikinci onayı sayılmaz, sahip olmayanlar onay veremez
+    token.approve_multisig_transaction(&owner1, &tx_id);
+    assert_eq!(token.wasm_hash(), None);
+    assert!(token.try_approve_multisig_transaction(&stranger, &tx_id).is_err());
+
+    // Sahibin imzası olmadan onay verilemez
+    e.mock_auths(&[]);
+    assert!(token.try_approve_multisig_transaction(&owner2, &tx_id).is_err());
+    e.mock_all_auths();
+
+    token.approve_multisig_transaction(&owner2, &tx_id);
+    assert_eq!(e.auths().len(), 1);
+    assert_eq!(e.auths()[0].0, owner2);
+
+    let sum: u64 = e.invoke_contract(&token.address, &symbol_short!("add"), vec![&e, 2_u64.into_val(&e), 3_u64.into_val(&e)]);
+    assert_eq!(sum, 5);
+}
+
+// Dağıtılmış ilk sürümün düzenini taklit eder: şema sürümü ve toplam arz yoktur,
+// dondurma bayrakları instance depolamada `true` olarak durur
+fn simulate_legacy_layout(e: &Env, token: &TokenClient, frozen_accounts: &[Address]) {
+    e.as_contract(&token.address, || {
+        e.storage().instance().remove(&DataKey::SchemaVersion);
+        e.storage().instance().remove(&DataKey::TotalSupply);
+        for account in frozen_accounts {
+            e.storage().instance().set(&DataKey::Frozen(account.clone()), &true);
+        }
+    });
+}
+
+#[test]
+fn migrate_moves_legacy_freeze_records() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let user3 = Address::generate(&e);
+    let user4 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    assert_eq!(token.schema_version(), 2);
+    token.mint(&admin, &user1, &1000);
+
+    simulate_legacy_layout(&e, &token, &[user1.clone(), user2.clone()]);
+    e.as_contract(&token.address, || {
+        e.storage().instance().set(&DataKey::Frozen(user4.clone()), &false);
+    });
+    assert_eq!(token.schema_version(), 1);
+
+    // Taşımadan önce de eski bayraklar dondurma olarak okunur
+    let legacy_record = FreezeRecord {
+        reason: LEGACY_FREEZE_REASON,
+        case_ref: BytesN::from_array(&e, &[0; 32]),
+        actor: token.address.clone(),
+        timestamp: 0,
+    };
+    assert_eq!(token.freeze_record(&user1), Some(legacy_record.clone()));
+    assert_eq!(token.freeze_record(&user4), None);
+    assert!(token.try_transfer(&user1, &user3, &1).is_err());
+
+    assert_eq!(token.total_supply(), 0);
+
+    token.migrate(&vec![&e, user1.clone(), user2.clone(), user3.clone(), user4.clone()], &vec![&e, user1.clone()], &1000);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (String::from_str(&e, "migrate"), admin.clone()).into_val(&e));
+    assert_eq!(<(u32, u32, i128)>::try_from_val(&e, &data).unwrap(), (1, 2, 1000));
+
+    assert_eq!(token.schema_version(), 2);
+    assert_eq!(token.total_supply(), 1000);
+    assert_eq!(token.freeze_record(&user1), Some(legacy_record.clone()));
+    assert_eq!(token.freeze_record(&user2), Some(legacy_record));
+    assert_eq!(token.freeze_record(&user3), None);
+    assert_eq!(token.freeze_record(&user4), None);
+    e.as_contract(&token.address, || {
+        for account in [&user1, &user2] {
+            let key = DataKey::Frozen(account.clone());
+            assert!(!e.storage().instance().has(&key));
+            assert!(e.storage().persistent().has(&key));
+        }
+        assert!(!e.storage().instance().has(&DataKey::Frozen(user4.clone())));
+    });
+
+    token.unfreeze_account(&admin, &user1);
+    assert_eq!(token.freeze_record(&user1), None);
+    token.transfer(&user1, &user3, &1);
+    token.burn(&user1, &999);
+    assert_eq!(token.total_supply(), 1);
+}
+
+#[test]
+#[should_panic(expected = "total supply is below known balances")]
+fn migrate_rejects_supply_below_known_balances() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user1 = Address::generate(&e);
+    let user2 = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user1, &600);
+    token.mint(&admin, &user2, &400);
+    simulate_legacy_layout(&e, &token, &[]);
+
+    token.migrate(&Vec::new(&e), &vec![&e, user1, user2], &999);
+}
+
+#[test]
+fn burn_cannot_drive_supply_negative() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    token.mint(&admin, &user, &100);
+    simulate_legacy_layout(&e, &token, &[]);
+
+    assert_eq!(token.try_burn(&user, &1), Err(Ok(TokenError::NegativeSupply.into())));
+    assert_eq!(token.try_clawback(&admin, &user, &1), Err(Ok(TokenError::NegativeSupply.into())));
+    assert_eq!(token.balance(&user), 100);
+}
+
+#[test]
+#[should_panic(expected = "storage already migrated")]
+fn migrate_twice() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    simulate_legacy_layout(&e, &token, &[]);
+
+    token.migrate(&Vec::new(&e), &Vec::new(&e), &0);
+    token.migrate(&Vec::new(&e), &Vec::new(&e), &0);
+}
+
+#[test]
+#[should_panic(expected = "storage already migrated")]
+fn migrate_current_layout() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+
+    token.migrate(&Vec::new(&e), &Vec::new(&e), &0);
+}
+
+#[test]
+#[should_panic(expected = "migrate requires multisig approval")]
+fn migrate_by_admin_when_multisig_enabled() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    setup_single_owner_multisig(&e, &token, &admin);
+    simulate_legacy_layout(&e, &token, &[]);
+
+    token.migrate(&Vec::new(&e), &Vec::new(&e), &0);
+}
+
+#[test]
+fn migrate_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+    token.mint(&admin, &user, &500);
+    simulate_legacy_layout(&e, &token, core::slice::from_ref(&user));
+
+    let tx_id = token.propose_migrate(&owner, &vec![&e, user.clone()], &vec![&e, user.clone()], &500, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+
+    assert_eq!(token.schema_version(), 2);
+    assert_eq!(token.total_supply(), 500);
+    e.as_contract(&token.address, || {
+        assert!(e.storage().persistent().has(&DataKey::Frozen(user.clone())));
+    });
+}
+
+fn airdrop_leaf(e: &Env, index: u32, account: &Address, amount: i128) -> BytesN<32> {
+    e.crypto().sha256(&(index, account.clone(), amount).to_xdr(e)).to_bytes()
+}
+
+fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    let mut pair = Bytes::from_array(e, &left.to_array());
+    pair.extend_from_array(&right.to_array());
+    e.crypto().sha256(&pair).to_bytes()
+}
+
+// Dört yapraklı Merkle ağacı; kökü ve her yaprağın kanıtını döndürür
+fn airdrop_tree(e: &Env, claims: &[(Address, i128); 4]) -> (BytesN<32>, std::vec::Vec<Vec<BytesN<32>>>) {
+    let leaves: std::vec::Vec<BytesN<32>> = claims
+        .iter()
+        .enumerate()
+        .map(|(i, (account, amount))| airdrop_leaf(e, i as u32, account, *amount))
+        .collect();
+    let left = hash_pair(e, &leaves[0], &leaves[1]);
+    let right = hash_pair(e, &leaves[2], &leaves[3]);
+    let root = hash_pair(e, &left, &right);
+    let proofs = std::vec![
+        vec![e, leaves[1].clone(), right.clone()],
+        vec![e, leaves[0].clone(), right.clone()],
+        vec![e, leaves[3].clone(), left.clone()],
+        vec![e, leaves[2].clone(), left.clone()],
+    ];
+    (root, proofs)
+}
+
+fn airdrop_claims(e: &Env) -> [(Address, i128); 4] {
+    [
+        (Address::generate(e), 100),
+        (Address::generate(e), 200),
+        (Address::generate(e), 300),
+        (Address::generate(e), 400),
+    ]
+}
+
+#[test]
+fn airdrop_claims_with_merkle_proof() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let claims = airdrop_claims(&e);
+    let (root, proofs) = airdrop_tree(&e, &claims);
+
+    token.set_airdrop(&root, &1000, &100);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (String::from_str(&e, "set_airdrop"), admin.clone()).into_val(&e));
+    assert_eq!(
+        <(u32, BytesN<32>, i128, u32)>::try_from_val(&e, &data).unwrap(),
+        (0, root.clone(), 1000, 100)
+    );
+    assert_eq!(token.total_supply(), 1000);
+    assert_eq!(token.balance(&token.address), 1000);
+
+    // Talep için hesabın yetkilendirmesi gerekmez
+    let (account, amount) = claims[2].clone();
+    token.claim(&2, &account, &amount, &proofs[2]);
+    assert_eq!(e.auths(), std::vec![]);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(topics, (symbol_short!("transfer"), token.address.clone(), account.clone()).into_val(&e));
+    assert_eq!(i128::try_from_val(&e, &data).unwrap(), 300);
+
+    let (other, other_amount) = claims[0].clone();
+    token.claim(&0, &other, &other_amount, &proofs[0]);
+
+    assert_eq!(token.balance(&account), 300);
+    assert_eq!(token.balance(&other), 100);
+    assert_eq!(token.balance(&token.address), 600);
+    assert!(token.is_airdrop_claimed(&0));
+    assert!(!token.is_airdrop_claimed(&1));
+    assert!(token.is_airdrop_claimed(&2));
+    assert_eq!(token.airdrop().unwrap().remaining, 600);
+}
+
+#[test]
+#[should_panic(expected = "airdrop already claimed")]
+fn airdrop_claim_twice() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let claims = airdrop_claims(&e);
+    let (root, proofs) = airdrop_tree(&e, &claims);
+    token.set_airdrop(&root, &1000, &100);
+
+    let (account, amount) = claims[1].clone();
+    token.claim(&1, &account, &amount, &proofs[1]);
+    token.claim(&1, &account, &amount, &proofs[1]);
+}
+
+#[test]
+#[should_panic(expected = "invalid merkle proof")]
+fn airdrop_claim_with_wrong_amount() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let claims = airdrop_claims(&e);
+    let (root, proofs) = airdrop_tree(&e, &claims);
+    token.set_airdrop(&root, &1000, &100);
+
+    let (account, _) = claims[1].clone();
+    token.claim(&1, &account, &201, &proofs[1]);
+}
+
+#[test]
+fn airdrop_reclaim_after_deadline() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let claims = airdrop_claims(&e);
+    let (root, proofs) = airdrop_tree(&e, &claims);
+    token.set_airdrop(&root, &1000, &100);
+
+    let (account, amount) = claims[3].clone();
+    token.claim(&3, &account, &amount, &proofs[3]);
+
+    e.ledger().set_sequence_number(101);
+    let (late, late_amount) = claims[0].clone();
+    assert!(token.try_claim(&0, &late, &late_amount, &proofs[0]).is_err());
+
+    token.reclaim_airdrop(&treasury);
+    let (_, topics, data) = e.events().all().last().unwrap();
+    assert_eq!(
+        topics,
+        (String::from_str(&e, "reclaim_airdrop"), admin.clone(), treasury.clone()).into_val(&e)
+    );
+    assert_eq!(<(u32, i128)>::try_from_val(&e, &data).unwrap(), (0, 600));
+    assert_eq!(token.balance(&treasury), 600);
+    assert_eq!(token.balance(&token.address), 0);
+    assert_eq!(token.total_supply(), 1000);
+
+    // Yeni airdrop ayrı bir talep bitmap'i kullanır
+    token.set_airdrop(&root, &1000, &200);
+    assert_eq!(token.airdrop().unwrap().id, 1);
+    assert!(!token.is_airdrop_claimed(&3));
+    token.claim(&3, &account, &amount, &proofs[3]);
+    assert_eq!(token.balance(&account), 800);
+}
+
+#[test]
+#[should_panic(expected = "airdrop still active")]
+fn airdrop_reclaim_before_deadline() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let (root, _) = airdrop_tree(&e, &airdrop_claims(&e));
+    token.set_airdrop(&root, &1000, &100);
+
+    token.reclaim_airdrop(&admin);
+}
+
+#[test]
+#[should_panic(expected = "airdrop already active")]
+fn airdrop_set_while_active() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let (root, _) = airdrop_tree(&e, &airdrop_claims(&e));
+    token.set_airdrop(&root, &1000, &100);
+    token.set_airdrop(&root, &1000, &100);
+}
+
+#[test]
+#[should_panic(expected = "set_airdrop requires multisig approval")]
+fn set_airdrop_by_admin_when_multisig_enabled() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    setup_single_owner_multisig(&e, &token, &admin);
+    let (root, _) = airdrop_tree(&e, &airdrop_claims(&e));
+
+    token.set_airdrop(&root, &1000, &100);
+}
+
+#[test]
+fn set_airdrop_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+    let claims = airdrop_claims(&e);
+    let (root, proofs) = airdrop_tree(&e, &claims);
+
+    let tx_id = token.propose_set_airdrop(&owner, &root, &1000, &100, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+    assert_eq!(token.balance(&token.address), 1000);
+
+    let (account, amount) = claims[1].clone();
+    token.claim(&1, &account, &amount, &proofs[1]);
+    assert_eq!(token.balance(&account), 200);
+}
+
+#[test]
+#[should_panic(expected = "nothing to reclaim")]
+fn airdrop_reclaim_twice() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let (root, _) = airdrop_tree(&e, &airdrop_claims(&e));
+    token.set_airdrop(&root, &1000, &100);
+
+    e.ledger().set_sequence_number(101);
+    token.reclaim_airdrop(&treasury);
+    token.reclaim_airdrop(&treasury);
+}
+
+#[test]
+fn airdrop_reclaim_while_transfers_paused() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let (root, _) = airdrop_tree(&e, &airdrop_claims(&e));
+    token.set_airdrop(&root, &1000, &100);
+
+    e.ledger().set_sequence_number(101);
+    token.pause(&admin);
+    assert!(token.try_reclaim_airdrop(&treasury).is_err());
+    assert_eq!(token.balance(&token.address), 1000);
+}
+
+#[test]
+#[should_panic(expected = "reclaim_airdrop requires multisig approval")]
+fn reclaim_airdrop_by_admin_when_multisig_enabled() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+    let (root, _) = airdrop_tree(&e, &airdrop_claims(&e));
+    let tx_id = token.propose_set_airdrop(&owner, &root, &1000, &100, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+
+    e.ledger().set_sequence_number(101);
+    token.reclaim_airdrop(&admin);
+}
+
+#[test]
+fn reclaim_airdrop_through_multisig() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let treasury = Address::generate(&e);
+    let token = create_token(&e, &admin);
+    let owner = setup_single_owner_multisig(&e, &token, &admin);
+    let claims = airdrop_claims(&e);
+    let (root, proofs) = airdrop_tree(&e, &claims);
+    let tx_id = token.propose_set_airdrop(&owner, &root, &1000, &100, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+
+    let (account, amount) = claims[1].clone();
+    token.claim(&1, &account, &amount, &proofs[1]);
+
+    e.ledger().set_sequence_number(101);
+    let tx_id = token.propose_reclaim_airdrop(&owner, &treasury, &1000);
+    token.approve_multisig_transaction(&owner, &tx_id);
+    assert_eq!(token.balance(&treasury), 800);
+    assert_eq!(token.balance(&token.address), 0);
+    assert_eq!(token.airdrop().unwrap().remaining, 0);
+}
+
+#[test]
+fn test_multisig_setup() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let owner3 = Address::generate(&env);
+    // Initialize token
+    let contract_id = env.register(
+        Token {},
+        (
+            admin.clone(),
+            10u32,
+            String::from_str(&env, "Test Token"),
+            String::from_str(&env, "TEST"),
+            None::<i128>,
+            None::<MultiSigConfig>,
+            None::<Vec<(Address, i128)>>,
+        ),
+    );
+    let token = TokenClient::new(&env, &contract_id);
+
+    // Setup multisig
+    let owners = Vec::from_array(&env, [owner1.clone(), owner2.clone(), owner3.clone()]);
+    env.mock_auths(&[MockAuth {
+        address: &admin,
+        invoke: &MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "setup_multisig",
+            args: (admin.clone(), owners.clone(), 2u32).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    token.setup_multisig(&admin, &owners, &2);
+
+    // Verify setup
+    let stored_owners: Vec<Address> = env.as_contract(&contract_id, || {
+        env.storage().instance().get(&DataKey::MultiSigOwners).unwrap()
+    });
+    let stored_threshold: u32 = env.as_contract(&contract_id, || {
+        env.storage().instance().get(&DataKey::MultiSigThreshold).unwrap()
+    });
+    assert_eq!(stored_owners.len(), 3);
+    assert_eq!(stored_threshold, 2);
+}
+
+
+
+#[test]
+#[should_panic(expected = "sender is not a multisig owner")]
+fn test_multisig_transaction_unauthorized() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let owner1 = Address::generate(&env);
+    let owner2 = Address::generate(&env);
+    let owner3 = Address::generate(&env);
+    let unauthorized = Address::generate(&env);
+    let target = Address::generate(&env);
+    // Initialize token
+    let contract_id = env.register(
+        Token {},
+        (
+            admin.clone(),
+            10u32,
+            String::from_str(&env, "Test Token"),
+            String::from_str(&env, "TEST"),
+            None::<i128>,
+            None::<MultiSigConfig>,
+            None::<Vec<(Address, i128)>>,
+        ),
+    );
+    let token = TokenClient::new(&env, &contract_id);
 
     // Setup multisig
     let owners = Vec::from_array(&env, [owner1.clone(), owner2.clone(), owner3.clone()]);
@@ -388,11 +2896,11 @@ fn test_multisig_transaction_unauthorized() {
         invoke: &MockAuthInvoke {
             contract: &contract_id,
             fn_name: "setup_multisig",
-            args: (owners.clone(), 2u32).into_val(&env),
+            args: (admin.clone(), owners.clone(), 2u32).into_val(&env),
             sub_invokes: &[],
         },
     }]);
-    token.setup_multisig(&owners, &2);
+    token.setup_multisig(&admin, &owners, &2);
 
     // Try to propose transaction with unauthorized user
     let operation = Symbol::new(&env, "transfer");
@@ -401,10 +2909,10 @@ fn test_multisig_transaction_unauthorized() {
         invoke: &MockAuthInvoke {
             contract: &contract_id,
             fn_name: "propose_multisig_transaction",
-            args: (operation.clone(), target.clone(), 100i128, 1000u64).into_val(&env),
+            args: (unauthorized.clone(), operation.clone(), target.clone(), 100i128, 1000u64).into_val(&env),
             sub_invokes: &[],
         },
     }]);
-    token.propose_multisig_transaction(&operation, &target, &100, &1000);
+    token.propose_multisig_transaction(&unauthorized, &operation, &target, &100, &1000);
 }
 
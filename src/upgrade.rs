@@ -0,0 +1,12 @@
+use crate::storage_types::DataKey;
+use soroban_sdk::{BytesN, Env};
+
+pub fn read_wasm_hash(e: &Env) -> Option<BytesN<32>> {
+    let key = DataKey::WasmHash;
+    e.storage().instance().get(&key)
+}
+
+pub fn write_wasm_hash(e: &Env, wasm_hash: &BytesN<32>) {
+    let key = DataKey::WasmHash;
+    e.storage().instance().set(&key, wasm_hash);
+}